@@ -80,3 +80,45 @@ pub const EIP1559_TX_TYPE_ID: u8 = 2;
 
 /// Identifier for an EIP4844 transaction.
 pub const EIP4844_TX_TYPE_ID: u8 = 3;
+
+/// Base gas cost of a transaction.
+pub const TX_GAS: u64 = 21_000;
+
+/// Base gas cost of a contract creation transaction.
+pub const TX_CREATE_GAS: u64 = 53_000;
+
+/// Gas cost per zero byte of transaction data.
+pub const TX_DATA_ZERO_GAS: u64 = 4;
+
+/// Gas cost per non-zero byte of transaction data ([EIP-2028]).
+///
+/// [EIP-2028]: https://eips.ethereum.org/EIPS/eip-2028
+pub const TX_DATA_NON_ZERO_GAS: u64 = 16;
+
+/// Gas cost per 32-byte word of contract creation init code ([EIP-3860]).
+///
+/// [EIP-3860]: https://eips.ethereum.org/EIPS/eip-3860
+pub const INIT_CODE_WORD_GAS: u64 = 2;
+
+/// Gas cost per address in an access list ([EIP-2930]).
+///
+/// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+pub const ACCESS_LIST_ADDRESS_GAS: u64 = 2_400;
+
+/// Gas cost per storage key in an access list ([EIP-2930]).
+///
+/// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+pub const ACCESS_LIST_STORAGE_KEY_GAS: u64 = 1_900;
+
+/// Gas cost per calldata token used for the calldata floor cost ([EIP-7623]).
+///
+/// A zero byte counts as one token and a non-zero byte as
+/// [`TX_DATA_NON_ZERO_TOKENS`] tokens.
+///
+/// [EIP-7623]: https://eips.ethereum.org/EIPS/eip-7623
+pub const TX_DATA_FLOOR_TOKEN_GAS: u64 = 10;
+
+/// Number of calldata tokens a non-zero byte counts as ([EIP-7623]).
+///
+/// [EIP-7623]: https://eips.ethereum.org/EIPS/eip-7623
+pub const TX_DATA_NON_ZERO_TOKENS: u64 = 4;
@@ -19,8 +19,12 @@ mod header;
 pub use header::{Header, EMPTY_OMMER_ROOT_HASH, EMPTY_ROOT_HASH};
 
 mod receipt;
+#[cfg(feature = "proto")]
+pub use receipt::ProtoDecodeError;
 pub use receipt::{
-    AnyReceiptEnvelope, Eip658Value, Receipt, ReceiptEnvelope, ReceiptWithBloom, TxReceipt,
+    logs_bloom, AnyReceiptEnvelope, BloomMismatch, CachedReceipt, Eip658Value, LazyBloomReceipt,
+    Receipt, ReceiptDiff, ReceiptEnvelope, ReceiptRlpIter, ReceiptSummary, ReceiptWithBloom,
+    TxReceipt,
 };
 
 mod request;
@@ -29,9 +33,14 @@ pub use request::Request;
 pub mod transaction;
 #[cfg(feature = "kzg")]
 pub use transaction::BlobTransactionValidationError;
+#[cfg(feature = "eip712")]
+pub use transaction::Eip712Transaction;
 pub use transaction::{
-    SignableTransaction, Transaction, TxEip1559, TxEip2930, TxEip4844, TxEip4844Variant,
-    TxEip4844WithSidecar, TxEnvelope, TxLegacy, TxType, TypedTransaction,
+    classify_transactions, gas_revenue_split, l1_data_gas, replaces, total_priority_payment,
+    AccessListUnsupported, BlobHashError, CallBundleEntry, ExpiringTx, FeeMarketUnsupported,
+    FeeValidationError, SignableTransaction, TokenOp, Transaction, TxBuildError, TxEip1559,
+    TxEip1559Builder, TxEip2930, TxEip2930Builder, TxEip4844, TxEip4844Variant,
+    TxEip4844WithSidecar, TxEnvelope, TxLegacy, TxLegacyBuilder, TxType, TypedTransaction,
 };
 
 pub use alloy_eips::eip4844::{
@@ -46,4 +55,6 @@ mod sealed;
 pub use sealed::{Sealable, Sealed};
 
 mod signed;
-pub use signed::Signed;
+#[cfg(feature = "k256")]
+pub use signed::RecoverSignerError;
+pub use signed::{Signed, TxAnnouncement};
@@ -7,11 +7,26 @@ mod envelope;
 pub use envelope::ReceiptEnvelope;
 
 mod receipts;
-pub use receipts::{Receipt, ReceiptWithBloom};
+pub use receipts::{
+    BloomMismatch, CachedReceipt, LazyBloomReceipt, Receipt, ReceiptDiff, ReceiptRlpIter,
+    ReceiptWithBloom,
+};
 
 mod status;
 pub use status::Eip658Value;
 
+#[cfg(feature = "proto")]
+mod proto;
+#[cfg(feature = "proto")]
+pub use proto::ProtoDecodeError;
+
+/// Calculates the bloom filter of the given logs.
+///
+/// This is the bloom filter of a [`Receipt`] emitting these logs, see [`Receipt::bloom_slow`].
+pub fn logs_bloom<'a>(logs: impl IntoIterator<Item = &'a Log>) -> Bloom {
+    logs.into_iter().collect()
+}
+
 /// Receipt is the result of a transaction execution.
 #[doc(alias = "TransactionReceipt")]
 pub trait TxReceipt<T = Log> {
@@ -56,6 +71,42 @@ pub trait TxReceipt<T = Log> {
 
     /// Returns the logs emitted by this transaction.
     fn logs(&self) -> &[T];
+
+    /// Returns true if the transaction emitted more than `n` logs.
+    fn has_logs_exceeding(&self, n: usize) -> bool {
+        self.logs().len() > n
+    }
+
+    /// Returns a [`ReceiptSummary`] of the receipt, e.g. to serve block receipts without their
+    /// logs.
+    ///
+    /// This uses the [cheap bloom](Self::bloom_cheap) if available.
+    fn summary(&self) -> ReceiptSummary {
+        ReceiptSummary {
+            status: self.status(),
+            cumulative_gas_used: self.cumulative_gas_used(),
+            log_count: self.logs().len(),
+            bloom: self.bloom_cheap().unwrap_or_else(|| self.bloom()),
+        }
+    }
+}
+
+/// A summary of a [`TxReceipt`] without its logs.
+///
+/// See [`TxReceipt::summary`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct ReceiptSummary {
+    /// Whether the transaction was successful, see [`TxReceipt::status`].
+    pub status: bool,
+    /// The cumulative gas used in the block after the transaction was executed.
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub cumulative_gas_used: u128,
+    /// The number of logs emitted by the transaction.
+    pub log_count: usize,
+    /// The bloom filter of the logs emitted by the transaction.
+    pub bloom: Bloom,
 }
 
 #[cfg(test)]
@@ -126,6 +177,28 @@ mod tests {
         assert_eq!(receipt, expected);
     }
 
+    #[test]
+    fn logs_bloom() {
+        let logs = vec![
+            Log::new_unchecked(
+                address!("0000000000000000000000000000000000000011"),
+                vec![b256!("000000000000000000000000000000000000000000000000000000000000dead")],
+                bytes!("0100ff"),
+            ),
+            Log::new_unchecked(
+                address!("0000000000000000000000000000000000000022"),
+                vec![b256!("000000000000000000000000000000000000000000000000000000000000beef")],
+                bytes!(""),
+            ),
+        ];
+        let receipt = Receipt { status: true.into(), cumulative_gas_used: 1, logs: logs.clone() };
+
+        let bloom = super::logs_bloom(&logs);
+        assert_eq!(bloom, receipt.bloom_slow());
+        assert_ne!(bloom, Bloom::ZERO);
+        assert_eq!(super::logs_bloom(&[]), Bloom::ZERO);
+    }
+
     #[test]
     fn gigantic_receipt() {
         let receipt = Receipt {
@@ -163,4 +236,53 @@ mod tests {
         // let (decoded, _) = Receipt::from_compact(&data[..], data.len());
         assert_eq!(decoded, receipt);
     }
+
+    #[test]
+    fn has_logs_exceeding() {
+        let receipts: Vec<_> = [1, 5, 10]
+            .into_iter()
+            .map(|n| Receipt {
+                status: true.into(),
+                cumulative_gas_used: 0,
+                logs: vec![Log::<LogData>::default(); n],
+            })
+            .collect();
+
+        let exceeding: Vec<_> = receipts
+            .iter()
+            .enumerate()
+            .filter(|(_, receipt)| receipt.has_logs_exceeding(4))
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(exceeding, [1, 2]);
+    }
+
+    #[test]
+    fn summary() {
+        let logs = vec![
+            Log::new_unchecked(
+                address!("0000000000000000000000000000000000000011"),
+                vec![b256!("000000000000000000000000000000000000000000000000000000000000dead")],
+                bytes!("0100ff"),
+            ),
+            Log::new_unchecked(
+                address!("0000000000000000000000000000000000000022"),
+                vec![],
+                bytes!(""),
+            ),
+        ];
+        let receipt = Receipt { status: true.into(), cumulative_gas_used: 42_000, logs };
+
+        let summary = receipt.summary();
+        assert_eq!(
+            summary,
+            ReceiptSummary {
+                status: true,
+                cumulative_gas_used: 42_000,
+                log_count: 2,
+                bloom: receipt.bloom_slow(),
+            }
+        );
+        assert_eq!(receipt.with_bloom().summary(), summary);
+    }
 }
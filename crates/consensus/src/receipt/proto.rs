@@ -0,0 +1,233 @@
+use crate::{Eip658Value, Receipt};
+use alloy_primitives::{Address, Bytes, Log, LogData, B256};
+use core::fmt;
+use prost::Message;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// The `Receipt` message of the protobuf schema.
+#[derive(Clone, PartialEq, Message)]
+struct ProtoReceipt {
+    #[prost(oneof = "ProtoStatus", tags = "1, 2")]
+    status: Option<ProtoStatus>,
+    #[prost(uint64, tag = "3")]
+    cumulative_gas_used: u64,
+    #[prost(message, repeated, tag = "4")]
+    logs: Vec<ProtoLog>,
+}
+
+/// The `status` oneof of the `Receipt` message.
+#[derive(Clone, PartialEq, prost::Oneof)]
+enum ProtoStatus {
+    #[prost(bool, tag = "1")]
+    Success(bool),
+    #[prost(bytes = "vec", tag = "2")]
+    PostState(Vec<u8>),
+}
+
+/// The `Log` message of the protobuf schema.
+#[derive(Clone, PartialEq, Message)]
+struct ProtoLog {
+    #[prost(bytes = "vec", tag = "1")]
+    address: Vec<u8>,
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    topics: Vec<Vec<u8>>,
+    #[prost(bytes = "vec", tag = "3")]
+    data: Vec<u8>,
+}
+
+impl Receipt {
+    /// Encodes the receipt as a protobuf `Receipt` message, e.g. to exchange receipts with
+    /// pipelines written in other languages.
+    ///
+    /// The bloom filter is not encoded, as it is derived from the logs. Cumulative gas values
+    /// above [`u64::MAX`], which cannot occur on Ethereum, are saturated.
+    ///
+    /// The schema, also available in `proto/receipt.proto`, is:
+    #[doc = concat!("```protobuf\n", include_str!("../../proto/receipt.proto"), "```")]
+    pub fn to_proto_bytes(&self) -> Vec<u8> {
+        let status = match self.status {
+            Eip658Value::Eip658(success) => ProtoStatus::Success(success),
+            Eip658Value::PostState(state) => ProtoStatus::PostState(state.to_vec()),
+        };
+        ProtoReceipt {
+            status: Some(status),
+            cumulative_gas_used: self.cumulative_gas_used.try_into().unwrap_or(u64::MAX),
+            logs: self
+                .logs
+                .iter()
+                .map(|log| ProtoLog {
+                    address: log.address.to_vec(),
+                    topics: log.topics().iter().map(|topic| topic.to_vec()).collect(),
+                    data: log.data.data.to_vec(),
+                })
+                .collect(),
+        }
+        .encode_to_vec()
+    }
+
+    /// Decodes a receipt from a protobuf `Receipt` message.
+    ///
+    /// See [`to_proto_bytes`](Self::to_proto_bytes) for the schema.
+    pub fn from_proto_bytes(bytes: &[u8]) -> Result<Self, ProtoDecodeError> {
+        let receipt = ProtoReceipt::decode(bytes).map_err(ProtoDecodeError::Decode)?;
+        let status = match receipt.status.ok_or(ProtoDecodeError::MissingStatus)? {
+            ProtoStatus::Success(success) => Eip658Value::Eip658(success),
+            ProtoStatus::PostState(state) => {
+                Eip658Value::PostState(fixed_bytes::<32>("post_state", &state)?.into())
+            }
+        };
+        let logs = receipt
+            .logs
+            .into_iter()
+            .map(|log| {
+                let address = Address::from(fixed_bytes::<20>("address", &log.address)?);
+                let topics = log
+                    .topics
+                    .iter()
+                    .map(|topic| fixed_bytes::<32>("topics", topic).map(B256::from))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let topic_count = topics.len();
+                let data = LogData::new(topics, Bytes::from(log.data))
+                    .ok_or(ProtoDecodeError::TooManyTopics(topic_count))?;
+                Ok(Log { address, data })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { status, cumulative_gas_used: receipt.cumulative_gas_used.into(), logs })
+    }
+}
+
+fn fixed_bytes<const N: usize>(
+    field: &'static str,
+    bytes: &[u8],
+) -> Result<[u8; N], ProtoDecodeError> {
+    bytes.try_into().map_err(|_| ProtoDecodeError::InvalidLength {
+        field,
+        expected: N,
+        actual: bytes.len(),
+    })
+}
+
+/// Error returned by [`Receipt::from_proto_bytes`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProtoDecodeError {
+    /// The bytes are not a valid protobuf message.
+    Decode(prost::DecodeError),
+    /// The receipt has no status.
+    MissingStatus,
+    /// A fixed-size field has the wrong length.
+    InvalidLength {
+        /// The name of the field.
+        field: &'static str,
+        /// The expected length in bytes.
+        expected: usize,
+        /// The actual length in bytes.
+        actual: usize,
+    },
+    /// A log has more than 4 topics.
+    TooManyTopics(usize),
+}
+
+impl fmt::Display for ProtoDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Decode(err) => write!(f, "invalid protobuf receipt: {err}"),
+            Self::MissingStatus => f.write_str("protobuf receipt has no status"),
+            Self::InvalidLength { field, expected, actual } => {
+                write!(
+                    f,
+                    "protobuf receipt field `{field}` has {actual} bytes, expected {expected}"
+                )
+            }
+            Self::TooManyTopics(count) => {
+                write!(f, "protobuf receipt log has {count} topics, expected at most 4")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ProtoDecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Decode(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256, bytes, hex};
+
+    #[test]
+    fn proto_roundtrip() {
+        let receipt = Receipt {
+            status: Eip658Value::PostState(B256::repeat_byte(0xee)),
+            cumulative_gas_used: 1_000_000,
+            logs: vec![
+                Log::new_unchecked(
+                    address!("6069a6c32cf691f5982febae4faf8a6f3ab2f0f6"),
+                    vec![B256::repeat_byte(1), B256::repeat_byte(2), B256::repeat_byte(3)],
+                    bytes!("deadbeef"),
+                ),
+                Log::new_unchecked(Address::ZERO, vec![], Bytes::new()),
+            ],
+        };
+        assert_eq!(Receipt::from_proto_bytes(&receipt.to_proto_bytes()).unwrap(), receipt);
+
+        let failed = Receipt { status: Eip658Value::Eip658(false), ..Default::default() };
+        assert_eq!(Receipt::from_proto_bytes(&failed.to_proto_bytes()).unwrap(), failed);
+    }
+
+    #[test]
+    fn proto_schema() {
+        let receipt = Receipt {
+            status: Eip658Value::Eip658(true),
+            cumulative_gas_used: 21_000,
+            logs: vec![Log::new_unchecked(
+                Address::repeat_byte(0x11),
+                vec![b256!("2222222222222222222222222222222222222222222222222222222222222222")],
+                bytes!("01"),
+            )],
+        };
+
+        // Hand-encoded against `proto/receipt.proto`, as `(tag << 3) | wire_type` keys followed
+        // by a varint or a length-prefixed value.
+        let log =
+            [&hex!("0a14")[..], &[0x11; 20], &hex!("1220"), &[0x22; 32], &hex!("1a0101")].concat();
+        let expected = [
+            &hex!("0801")[..],        // success = true
+            &hex!("1888a401"),        // cumulative_gas_used = 21000
+            &[0x22, log.len() as u8], // logs[0]
+            &log,
+        ]
+        .concat();
+        assert_eq!(receipt.to_proto_bytes(), expected);
+    }
+
+    #[test]
+    fn proto_decode_errors() {
+        // Empty message: no status.
+        assert_eq!(Receipt::from_proto_bytes(&[]), Err(ProtoDecodeError::MissingStatus));
+        // post_state of 1 byte.
+        assert_eq!(
+            Receipt::from_proto_bytes(&hex!("1201ff")),
+            Err(ProtoDecodeError::InvalidLength { field: "post_state", expected: 32, actual: 1 })
+        );
+        // Truncated message.
+        assert!(matches!(
+            Receipt::from_proto_bytes(&hex!("22ff")),
+            Err(ProtoDecodeError::Decode(_))
+        ));
+
+        let log = Log::new_unchecked(Address::ZERO, vec![B256::ZERO; 5], Bytes::new());
+        let receipt = Receipt { status: true.into(), logs: vec![log], ..Default::default() };
+        assert_eq!(
+            Receipt::from_proto_bytes(&receipt.to_proto_bytes()),
+            Err(ProtoDecodeError::TooManyTopics(5))
+        );
+    }
+}
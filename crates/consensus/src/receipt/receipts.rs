@@ -1,12 +1,15 @@
-use crate::receipt::{Eip658Value, TxReceipt};
-use alloy_primitives::{Bloom, Log};
+use crate::receipt::{logs_bloom, Eip658Value, TxReceipt};
+use alloy_primitives::{keccak256, Bloom, Log, B256};
 use alloy_rlp::{length_of_length, BufMut, Decodable, Encodable};
-use core::borrow::Borrow;
+use core::{borrow::Borrow, fmt};
 
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
 
 /// Receipt containing result of transaction execution.
+///
+/// When deserializing, unknown fields are ignored, so a JSON-RPC transaction receipt can be
+/// deserialized directly into this type. See also [`Receipt::from_rpc_lenient`].
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 #[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
@@ -53,6 +56,78 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+impl<T: serde::de::DeserializeOwned> Receipt<T> {
+    /// Deserializes the consensus receipt from a JSON-RPC transaction receipt object.
+    ///
+    /// Only the consensus fields (`status` or `root`, `cumulativeGasUsed` and `logs`) are
+    /// extracted, all other fields such as `transactionHash`, `effectiveGasPrice` or `type` are
+    /// ignored.
+    pub fn from_rpc_lenient(receipt: serde_json::Value) -> Result<Self, serde_json::Error> {
+        use serde::de::Error;
+
+        let serde_json::Value::Object(mut fields) = receipt else {
+            return Err(serde_json::Error::custom("expected a receipt object"));
+        };
+        let consensus_fields = ["status", "root", "cumulativeGasUsed", "logs"]
+            .into_iter()
+            .filter_map(|key| Some((key.into(), fields.remove(key)?)))
+            .collect::<serde_json::Map<_, _>>();
+        serde_json::from_value(consensus_fields.into())
+    }
+}
+
+impl<T> Receipt<T> {
+    /// Creates a new receipt from the output of executing a transaction: whether the execution
+    /// succeeded, the cumulative gas used in the block after the transaction, and the logs the
+    /// execution emitted.
+    ///
+    /// The success flag becomes the [EIP-658] status code of the receipt. See
+    /// [`from_execution_with_status`](Self::from_execution_with_status) for pre-[EIP-658]
+    /// receipts carrying a post-state root.
+    ///
+    /// [EIP-658]: https://eips.ethereum.org/EIPS/eip-658
+    pub const fn from_execution(success: bool, cumulative_gas_used: u128, logs: Vec<T>) -> Self {
+        Self::from_execution_with_status(Eip658Value::Eip658(success), cumulative_gas_used, logs)
+    }
+
+    /// Creates a new receipt from the output of executing a transaction, like
+    /// [`from_execution`](Self::from_execution), but with an explicit status, e.g. the
+    /// [post-state root](Eip658Value::PostState) of a pre-[EIP-658] transaction.
+    ///
+    /// [EIP-658]: https://eips.ethereum.org/EIPS/eip-658
+    pub const fn from_execution_with_status(
+        status: Eip658Value,
+        cumulative_gas_used: u128,
+        logs: Vec<T>,
+    ) -> Self {
+        Self { status, cumulative_gas_used, logs }
+    }
+
+    /// Creates a new receipt whose cumulative gas used is the cumulative gas used of the previous
+    /// receipt in the block plus the gas used by this transaction.
+    ///
+    /// For the first transaction of a block, `prev_cumulative` is `0`. Returns `None` if the
+    /// addition overflows.
+    pub fn new_cumulative(
+        status: impl Into<Eip658Value>,
+        prev_cumulative: u128,
+        gas_used: u128,
+        logs: Vec<T>,
+    ) -> Option<Self> {
+        let cumulative_gas_used = prev_cumulative.checked_add(gas_used)?;
+        Some(Self { status: status.into(), cumulative_gas_used, logs })
+    }
+
+    /// Returns the post-transaction state root of a pre-[EIP-658] receipt, or `None` if the
+    /// receipt carries an [EIP-658] status code instead.
+    ///
+    /// [EIP-658]: https://eips.ethereum.org/EIPS/eip-658
+    pub const fn post_state_root(&self) -> Option<B256> {
+        self.status.as_post_state()
+    }
+}
+
 impl<T> Receipt<T>
 where
     T: Borrow<Log>,
@@ -60,7 +135,7 @@ where
     /// Calculates [`Log`]'s bloom filter. this is slow operation and [ReceiptWithBloom] can
     /// be used to cache this value.
     pub fn bloom_slow(&self) -> Bloom {
-        self.logs.iter().map(Borrow::borrow).collect()
+        logs_bloom(self.logs.iter().map(Borrow::borrow))
     }
 
     /// Calculates the bloom filter for the receipt and returns the [ReceiptWithBloom] container
@@ -68,8 +143,157 @@ where
     pub fn with_bloom(self) -> ReceiptWithBloom<T> {
         self.into()
     }
+
+    /// Recomputes the bloom filter of the receipt and checks that it matches the `expected` one,
+    /// e.g. the value committed to in a block.
+    ///
+    /// The error is boxed, as [`BloomMismatch`] holds both 256 byte blooms: this keeps the
+    /// returned `Result` pointer-sized on the common, matching path.
+    pub fn assert_bloom(&self, expected: &Bloom) -> Result<(), Box<BloomMismatch>> {
+        let computed = self.bloom_slow();
+        if computed == *expected {
+            Ok(())
+        } else {
+            Err(Box::new(BloomMismatch { expected: *expected, computed }))
+        }
+    }
+
+    /// Decodes the logs of the receipt emitting the event `E`, e.g. a [`sol!`] generated event.
+    ///
+    /// Logs are matched by their first topic against [`E::SIGNATURE_HASH`], and the other logs
+    /// are skipped, so anonymous events are never matched. The results are in log order.
+    ///
+    /// [`sol!`]: alloy_sol_types::sol
+    /// [`E::SIGNATURE_HASH`]: alloy_sol_types::SolEvent::SIGNATURE_HASH
+    #[cfg(feature = "sol-types")]
+    pub fn decode_events<E: alloy_sol_types::SolEvent>(
+        &self,
+    ) -> Vec<Result<E, alloy_sol_types::Error>> {
+        self.logs
+            .iter()
+            .map(Borrow::borrow)
+            .filter(|log: &&Log| log.topics().first() == Some(&E::SIGNATURE_HASH))
+            .map(|log| E::decode_log_data(&log.data, true))
+            .collect()
+    }
+}
+
+impl<T: Encodable> Receipt<T> {
+    /// Returns the RLP payload length of the receipt fields, excluding the bloom filter.
+    fn payload_len_without_bloom(&self) -> usize {
+        self.status.length() + self.cumulative_gas_used.length() + self.logs.length()
+    }
+
+    /// Encodes the receipt as the RLP list `[status, cumulative_gas_used, logs]`, omitting the
+    /// bloom filter.
+    ///
+    /// This is **not** the consensus receipt encoding, which always includes the bloom filter
+    /// (see [`ReceiptWithBloom`]). It is only meant for specialized hashing schemes where the
+    /// bloom, being derived from the logs, is redundant.
+    pub fn encode_without_bloom(&self, out: &mut dyn BufMut) {
+        alloy_rlp::Header { list: true, payload_length: self.payload_len_without_bloom() }
+            .encode(out);
+        self.status.encode(out);
+        self.cumulative_gas_used.encode(out);
+        self.logs.encode(out);
+    }
+
+    /// Returns the length of the [`encode_without_bloom`](Self::encode_without_bloom) encoding.
+    pub fn encoded_len_without_bloom(&self) -> usize {
+        let payload_length = self.payload_len_without_bloom();
+        payload_length + length_of_length(payload_length)
+    }
+
+    /// Returns a deterministic identifier of the receipt content, e.g. to key a receipt cache.
+    ///
+    /// This is the `keccak256` hash of the [`encode_without_bloom`](Self::encode_without_bloom)
+    /// encoding, so it only depends on the status, cumulative gas used and logs: receipts with
+    /// the same content share an id regardless of any bloom filter stored alongside them.
+    pub fn content_id(&self) -> B256 {
+        let mut out = Vec::with_capacity(self.encoded_len_without_bloom());
+        self.encode_without_bloom(&mut out);
+        keccak256(out)
+    }
+}
+
+impl<T: Encodable + Borrow<Log>> Receipt<T> {
+    /// Encodes the receipt as the light client RLP list
+    /// `[status, cumulative_gas_used, keccak256(bloom), logs]`.
+    ///
+    /// This trades the 256 byte bloom filter of the consensus encoding (see [`ReceiptWithBloom`])
+    /// for its 32 byte hash, e.g. for light clients that verify logs against a known bloom hash
+    /// but do not need the bloom itself. The bloom is computed from the logs, see
+    /// [`bloom_slow`](Self::bloom_slow). Decode with [`decode_light`](Self::decode_light).
+    ///
+    /// This is **not** the consensus receipt encoding, and cannot be used to compute receipt
+    /// roots.
+    pub fn encode_light(&self, out: &mut dyn BufMut) {
+        alloy_rlp::Header { list: true, payload_length: self.payload_len_light() }.encode(out);
+        self.status.encode(out);
+        self.cumulative_gas_used.encode(out);
+        keccak256(self.bloom_slow()).encode(out);
+        self.logs.encode(out);
+    }
+
+    /// Returns the length of the [`encode_light`](Self::encode_light) encoding.
+    pub fn encoded_len_light(&self) -> usize {
+        let payload_length = self.payload_len_light();
+        payload_length + length_of_length(payload_length)
+    }
+
+    fn payload_len_light(&self) -> usize {
+        self.payload_len_without_bloom() + B256::ZERO.length()
+    }
+}
+
+impl<T: Decodable + Borrow<Log>> Receipt<T> {
+    /// Decodes a receipt from the [`encode_light`](Self::encode_light) encoding.
+    ///
+    /// Returns an error if the bloom hash does not match the bloom filter of the decoded logs.
+    pub fn decode_light(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let header = alloy_rlp::Header::decode(buf)?;
+        if !header.list {
+            return Err(alloy_rlp::Error::UnexpectedString);
+        }
+        let remaining = buf.len();
+        let status = Decodable::decode(buf)?;
+        let cumulative_gas_used = Decodable::decode(buf)?;
+        let bloom_hash = B256::decode(buf)?;
+        let logs = Decodable::decode(buf)?;
+        if remaining - buf.len() != header.payload_length {
+            return Err(alloy_rlp::Error::ListLengthMismatch {
+                expected: header.payload_length,
+                got: remaining - buf.len(),
+            });
+        }
+
+        let receipt = Self { status, cumulative_gas_used, logs };
+        if keccak256(receipt.bloom_slow()) != bloom_hash {
+            return Err(alloy_rlp::Error::Custom("light receipt bloom hash mismatch"));
+        }
+        Ok(receipt)
+    }
+}
+
+/// Error returned by [`Receipt::assert_bloom`] when the computed bloom filter does not match the
+/// expected one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BloomMismatch {
+    /// The expected bloom filter.
+    pub expected: Bloom,
+    /// The bloom filter computed from the receipt logs.
+    pub computed: Bloom,
 }
 
+impl fmt::Display for BloomMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "receipt bloom mismatch: expected {}, computed {}", self.expected, self.computed)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BloomMismatch {}
+
 impl<T> TxReceipt<T> for Receipt<T>
 where
     T: Borrow<Log>,
@@ -165,6 +389,11 @@ impl<T: Encodable> ReceiptWithBloom<T> {
     /// Encodes the receipt data.
     fn encode_fields(&self, out: &mut dyn BufMut) {
         self.receipt_rlp_header().encode(out);
+        self.encode_payload(out);
+    }
+
+    /// Encodes the receipt fields, without the list header.
+    fn encode_payload(&self, out: &mut dyn BufMut) {
         self.receipt.status.encode(out);
         self.receipt.cumulative_gas_used.encode(out);
         self.logs_bloom.encode(out);
@@ -222,6 +451,47 @@ impl<T> ReceiptWithBloom<T> {
     }
 }
 
+impl<T: PartialEq> ReceiptWithBloom<T> {
+    /// Compares this receipt with `other` field by field.
+    ///
+    /// Unlike [`PartialEq`], this reports which fields differ, e.g. to tell apart receipts whose
+    /// logs differ from receipts that only disagree on the bloom filter.
+    pub fn diff(&self, other: &Self) -> ReceiptDiff {
+        ReceiptDiff {
+            status: self.receipt.status != other.receipt.status,
+            cumulative_gas_used: self.receipt.cumulative_gas_used
+                != other.receipt.cumulative_gas_used,
+            logs: self.receipt.logs != other.receipt.logs,
+            logs_bloom: self.logs_bloom != other.logs_bloom,
+        }
+    }
+}
+
+/// The fields that differ between two receipts, as returned by [`ReceiptWithBloom::diff`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ReceiptDiff {
+    /// Whether the status or post state differs.
+    pub status: bool,
+    /// Whether the cumulative gas used differs.
+    pub cumulative_gas_used: bool,
+    /// Whether the logs differ.
+    pub logs: bool,
+    /// Whether the bloom filter differs.
+    pub logs_bloom: bool,
+}
+
+impl ReceiptDiff {
+    /// Returns `true` if no field differs.
+    pub const fn is_empty(&self) -> bool {
+        !(self.status || self.cumulative_gas_used || self.logs || self.logs_bloom)
+    }
+
+    /// Returns `true` if the bloom filter is the only field that differs.
+    pub const fn is_bloom_only(&self) -> bool {
+        self.logs_bloom && !(self.status || self.cumulative_gas_used || self.logs)
+    }
+}
+
 impl<T: Encodable> Encodable for ReceiptWithBloom<T> {
     fn encode(&self, out: &mut dyn BufMut) {
         self.encode_fields(out);
@@ -242,6 +512,247 @@ impl<T: Decodable> Decodable for ReceiptWithBloom<T> {
     }
 }
 
+/// The cache of the lazily computed receipt fields.
+///
+/// This is a [`OnceLock`](std::sync::OnceLock) with the `std` feature, so that the receipt types
+/// using it are `Sync`, and a [`OnceCell`](core::cell::OnceCell) otherwise.
+#[cfg(feature = "std")]
+type OnceCache<T> = std::sync::OnceLock<T>;
+#[cfg(not(feature = "std"))]
+type OnceCache<T> = core::cell::OnceCell<T>;
+
+/// [`Receipt`] with a lazily calculated bloom filter.
+///
+/// Unlike [`ReceiptWithBloom`], which computes the bloom filter eagerly on construction, this
+/// type defers the computation until the first call to [`bloom`](Self::bloom) and caches the
+/// result. This is useful for code paths that may never read the bloom, e.g. log scans.
+///
+/// With the `std` feature the cache is thread-safe and the type is `Sync`, e.g. to share it
+/// behind an `Arc`. Without `std` it is `!Sync`.
+#[derive(Clone, Debug, Default)]
+#[doc(alias = "LazyReceiptWithBloom")]
+pub struct LazyBloomReceipt<T = Log> {
+    /// The receipt.
+    receipt: Receipt<T>,
+    /// The bloom filter, computed on first access.
+    bloom: OnceCache<Bloom>,
+}
+
+impl<T> LazyBloomReceipt<T> {
+    /// Create a new [LazyBloomReceipt] without computing the bloom filter.
+    pub const fn new(receipt: Receipt<T>) -> Self {
+        Self { receipt, bloom: OnceCache::new() }
+    }
+
+    /// Returns a reference to the inner receipt.
+    pub const fn receipt(&self) -> &Receipt<T> {
+        &self.receipt
+    }
+
+    /// Consume the structure, returning only the receipt.
+    pub fn into_receipt(self) -> Receipt<T> {
+        self.receipt
+    }
+
+    /// Returns the bloom filter if it has already been computed.
+    pub fn cached_bloom(&self) -> Option<Bloom> {
+        self.bloom.get().copied()
+    }
+}
+
+impl<T> LazyBloomReceipt<T>
+where
+    T: Borrow<Log>,
+{
+    /// Returns the bloom filter, computing and caching it on first access.
+    pub fn bloom(&self) -> Bloom {
+        *self.bloom.get_or_init(|| self.receipt.bloom_slow())
+    }
+
+    /// Consume the structure, returning a [ReceiptWithBloom]. This computes the bloom filter if
+    /// it has not been accessed yet.
+    pub fn into_receipt_with_bloom(self) -> ReceiptWithBloom<T> {
+        let logs_bloom = self.bloom();
+        ReceiptWithBloom { receipt: self.receipt, logs_bloom }
+    }
+}
+
+impl<T> From<Receipt<T>> for LazyBloomReceipt<T> {
+    fn from(receipt: Receipt<T>) -> Self {
+        Self::new(receipt)
+    }
+}
+
+impl<T> From<ReceiptWithBloom<T>> for LazyBloomReceipt<T> {
+    fn from(receipt: ReceiptWithBloom<T>) -> Self {
+        let (receipt, logs_bloom) = receipt.into_components();
+        Self { receipt, bloom: OnceCache::from(logs_bloom) }
+    }
+}
+
+// The bloom filter is derived from the logs, so it is not compared.
+impl<T: PartialEq> PartialEq for LazyBloomReceipt<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.receipt == other.receipt
+    }
+}
+
+impl<T: Eq> Eq for LazyBloomReceipt<T> {}
+
+impl<T> TxReceipt<T> for LazyBloomReceipt<T>
+where
+    T: Borrow<Log>,
+{
+    fn status_or_post_state(&self) -> &Eip658Value {
+        &self.receipt.status
+    }
+
+    fn status(&self) -> bool {
+        self.receipt.status.coerce_status()
+    }
+
+    fn bloom(&self) -> Bloom {
+        self.bloom()
+    }
+
+    fn bloom_cheap(&self) -> Option<Bloom> {
+        self.cached_bloom()
+    }
+
+    fn cumulative_gas_used(&self) -> u128 {
+        self.receipt.cumulative_gas_used
+    }
+
+    fn logs(&self) -> &[T] {
+        &self.receipt.logs
+    }
+}
+
+/// [`ReceiptWithBloom`] with a cached RLP payload length.
+///
+/// Computing the RLP length of a receipt walks all of its logs, and encoding it does so again to
+/// build the list header. This wrapper memoizes the payload length on first use, so repeated
+/// [`length`](Encodable::length) and [`encode`](Encodable::encode) calls, e.g. when building a
+/// receipts trie, don't recompute it. The cache is cleared whenever the receipt is mutably
+/// borrowed through [`receipt_mut`](Self::receipt_mut).
+///
+/// With the `std` feature the cache is thread-safe and the type is `Sync`. Without `std` it is
+/// `!Sync`.
+#[derive(Clone, Debug, Default)]
+pub struct CachedReceipt<T = Log> {
+    /// The receipt.
+    receipt: ReceiptWithBloom<T>,
+    /// The RLP payload length, computed on first access.
+    payload_length: OnceCache<usize>,
+}
+
+impl<T> CachedReceipt<T> {
+    /// Create a new [CachedReceipt] without computing the length.
+    pub const fn new(receipt: ReceiptWithBloom<T>) -> Self {
+        Self { receipt, payload_length: OnceCache::new() }
+    }
+
+    /// Returns a reference to the inner receipt.
+    pub const fn receipt(&self) -> &ReceiptWithBloom<T> {
+        &self.receipt
+    }
+
+    /// Returns a mutable reference to the inner receipt, invalidating the cached length.
+    pub fn receipt_mut(&mut self) -> &mut ReceiptWithBloom<T> {
+        self.payload_length.take();
+        &mut self.receipt
+    }
+
+    /// Consume the structure, returning the inner receipt.
+    pub fn into_receipt(self) -> ReceiptWithBloom<T> {
+        self.receipt
+    }
+
+    /// Returns the RLP payload length if it has already been computed.
+    pub fn cached_payload_length(&self) -> Option<usize> {
+        self.payload_length.get().copied()
+    }
+}
+
+impl<T: Encodable> CachedReceipt<T> {
+    /// Returns the RLP payload length, computing and caching it on first access.
+    pub fn payload_length(&self) -> usize {
+        *self.payload_length.get_or_init(|| self.receipt.payload_len())
+    }
+}
+
+impl<T> From<ReceiptWithBloom<T>> for CachedReceipt<T> {
+    fn from(receipt: ReceiptWithBloom<T>) -> Self {
+        Self::new(receipt)
+    }
+}
+
+// The length is derived from the receipt, so it is not compared.
+impl<T: PartialEq> PartialEq for CachedReceipt<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.receipt == other.receipt
+    }
+}
+
+impl<T: Eq> Eq for CachedReceipt<T> {}
+
+impl<T: Encodable> Encodable for CachedReceipt<T> {
+    fn encode(&self, out: &mut dyn BufMut) {
+        alloy_rlp::Header { list: true, payload_length: self.payload_length() }.encode(out);
+        self.receipt.encode_payload(out);
+    }
+
+    fn length(&self) -> usize {
+        let payload_length = self.payload_length();
+        payload_length + length_of_length(payload_length)
+    }
+}
+
+impl<T: Decodable> Decodable for CachedReceipt<T> {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        ReceiptWithBloom::decode(buf).map(Self::new)
+    }
+}
+
+/// Iterator over RLP-encoded [`ReceiptWithBloom`]s stored back to back in a buffer.
+///
+/// Each call to [`next`](Iterator::next) decodes one receipt and advances past it. The iterator
+/// ends once the buffer is exhausted, or after yielding the first decoding error.
+#[derive(Clone, Debug)]
+pub struct ReceiptRlpIter<'a, T = Log> {
+    buf: &'a [u8],
+    _marker: core::marker::PhantomData<fn() -> T>,
+}
+
+impl<'a, T> ReceiptRlpIter<'a, T> {
+    /// Create a new [ReceiptRlpIter] over the given buffer.
+    pub const fn new(buf: &'a [u8]) -> Self {
+        Self { buf, _marker: core::marker::PhantomData }
+    }
+
+    /// Returns the part of the buffer that has not been decoded yet.
+    pub const fn remaining(&self) -> &'a [u8] {
+        self.buf
+    }
+}
+
+impl<T: Decodable> Iterator for ReceiptRlpIter<'_, T> {
+    type Item = alloy_rlp::Result<ReceiptWithBloom<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buf.is_empty() {
+            return None;
+        }
+        let result = ReceiptWithBloom::decode_receipt(&mut self.buf);
+        if result.is_err() {
+            self.buf = &[];
+        }
+        Some(result)
+    }
+}
+
+impl<T: Decodable> core::iter::FusedIterator for ReceiptRlpIter<'_, T> {}
+
 #[cfg(any(test, feature = "arbitrary"))]
 impl<'a, T> arbitrary::Arbitrary<'a> for ReceiptWithBloom<T>
 where
@@ -254,6 +765,423 @@ where
 
 #[cfg(test)]
 mod test {
+    use super::*;
+    use alloy_primitives::{address, b256, bytes, Address, Bytes, LogData};
+    use core::cell::Cell;
+
+    /// A log that counts how many times it has been borrowed as a [`Log`].
+    #[derive(Debug)]
+    struct CountingLog<'a> {
+        log: Log,
+        borrows: &'a Cell<usize>,
+    }
+
+    impl Borrow<Log> for CountingLog<'_> {
+        fn borrow(&self) -> &Log {
+            self.borrows.set(self.borrows.get() + 1);
+            &self.log
+        }
+    }
+
+    #[test]
+    fn assert_bloom() {
+        let receipt = Receipt {
+            status: true.into(),
+            cumulative_gas_used: 1,
+            logs: vec![Log {
+                address: address!("0000000000000000000000000000000000000011"),
+                data: LogData::new_unchecked(
+                    vec![b256!("000000000000000000000000000000000000000000000000000000000000dead")],
+                    bytes!("0100ff"),
+                ),
+            }],
+        };
+        let bloom = receipt.bloom_slow();
+        assert_eq!(receipt.assert_bloom(&bloom), Ok(()));
+
+        let wrong = Bloom::default();
+        assert_eq!(
+            receipt.assert_bloom(&wrong),
+            Err(Box::new(BloomMismatch { expected: wrong, computed: bloom }))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn lazy_receipts_are_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<LazyBloomReceipt>();
+        assert_sync::<CachedReceipt>();
+    }
+
+    #[test]
+    fn lazy_bloom_is_computed_on_first_access() {
+        let borrows = Cell::new(0);
+        let log = Log {
+            address: address!("0000000000000000000000000000000000000011"),
+            data: LogData::new_unchecked(
+                vec![b256!("000000000000000000000000000000000000000000000000000000000000dead")],
+                bytes!("0100ff"),
+            ),
+        };
+        let expected =
+            Receipt { status: true.into(), cumulative_gas_used: 1, logs: vec![&log] }.bloom_slow();
+
+        let receipt = LazyBloomReceipt::new(Receipt {
+            status: true.into(),
+            cumulative_gas_used: 1,
+            logs: vec![CountingLog { log: log.clone(), borrows: &borrows }],
+        });
+
+        // reading the logs does not compute the bloom
+        assert_eq!(receipt.logs().len(), 1);
+        assert_eq!(receipt.cached_bloom(), None);
+        assert_eq!(borrows.get(), 0);
+
+        assert_eq!(receipt.bloom(), expected);
+        assert_eq!(borrows.get(), 1);
+
+        // the bloom is cached after the first access
+        assert_eq!(receipt.bloom(), expected);
+        assert_eq!(receipt.cached_bloom(), Some(expected));
+        assert_eq!(borrows.get(), 1);
+    }
+
+    #[test]
+    fn from_execution() {
+        let logs = vec![Log::new_unchecked(Address::repeat_byte(1), vec![], Bytes::new())];
+        let receipt = Receipt::<Log>::from_execution(true, 21_000, logs.clone());
+        assert_eq!(receipt.status, Eip658Value::Eip658(true));
+        assert_eq!(receipt.cumulative_gas_used, 21_000);
+        assert_eq!(receipt.logs, logs);
+        assert!(receipt.status());
+
+        let failed = Receipt::<Log>::from_execution(false, 42_000, vec![]);
+        assert!(!failed.status());
+        assert_eq!(failed.post_state_root(), None);
+
+        let root = B256::repeat_byte(0xee);
+        let receipt =
+            Receipt::<Log>::from_execution_with_status(Eip658Value::PostState(root), 30_000, logs);
+        assert_eq!(receipt.post_state_root(), Some(root));
+        assert_eq!(receipt.cumulative_gas_used, 30_000);
+        assert!(receipt.status());
+    }
+
+    #[test]
+    fn new_cumulative() {
+        let first = Receipt::<Log>::new_cumulative(true, 0, 21_000, vec![]).unwrap();
+        let second =
+            Receipt::<Log>::new_cumulative(false, first.cumulative_gas_used, 50_000, vec![])
+                .unwrap();
+        let third =
+            Receipt::<Log>::new_cumulative(true, second.cumulative_gas_used, 30_000, vec![])
+                .unwrap();
+        assert_eq!(first.cumulative_gas_used, 21_000);
+        assert_eq!(second.cumulative_gas_used, 71_000);
+        assert_eq!(third.cumulative_gas_used, 101_000);
+        assert_eq!(second.status, Eip658Value::Eip658(false));
+
+        assert_eq!(Receipt::<Log>::new_cumulative(true, u128::MAX, 1, vec![]), None);
+    }
+
+    #[test]
+    fn encode_without_bloom() {
+        let receipt = Receipt {
+            status: true.into(),
+            cumulative_gas_used: 1,
+            logs: vec![Log {
+                address: address!("0000000000000000000000000000000000000011"),
+                data: LogData::new_unchecked(
+                    vec![b256!("000000000000000000000000000000000000000000000000000000000000dead")],
+                    bytes!("0100ff"),
+                ),
+            }],
+        };
+
+        let mut out = Vec::new();
+        receipt.encode_without_bloom(&mut out);
+        assert_eq!(out.len(), receipt.encoded_len_without_bloom());
+
+        let mut buf = out.as_slice();
+        let header = alloy_rlp::Header::decode(&mut buf).unwrap();
+        assert!(header.list);
+
+        // the payload omits the bloom, a 256 byte string with a 3 byte header
+        let with_bloom = alloy_rlp::encode(receipt.clone().with_bloom());
+        let header_with_bloom = alloy_rlp::Header::decode(&mut with_bloom.as_slice()).unwrap();
+        assert_eq!(header_with_bloom.payload_length - header.payload_length, 256 + 3);
+
+        assert_eq!(Eip658Value::decode(&mut buf).unwrap(), receipt.status);
+        assert_eq!(u128::decode(&mut buf).unwrap(), receipt.cumulative_gas_used);
+        assert_eq!(Vec::<Log>::decode(&mut buf).unwrap(), receipt.logs);
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "sol-types")]
+    fn decode_events() {
+        alloy_sol_types::sol! {
+            event Transfer(address indexed from, address indexed to, uint256 value);
+            event Approval(address indexed owner, address indexed spender, uint256 value);
+        }
+
+        let from = address!("0000000000000000000000000000000000000011");
+        let to = address!("0000000000000000000000000000000000000022");
+        let token = address!("dac17f958d2ee523a2206206994597c13d831ec7");
+        let transfer = Transfer { from, to, value: alloy_primitives::U256::from(5) };
+        let approval = Approval { owner: from, spender: to, value: alloy_primitives::U256::MAX };
+        let log = |data: LogData| Log { address: token, data };
+
+        let receipt = Receipt::from_execution(
+            true,
+            100,
+            vec![
+                log(alloy_sol_types::SolEvent::encode_log_data(&approval)),
+                log(alloy_sol_types::SolEvent::encode_log_data(&transfer)),
+                log(LogData::new_unchecked(vec![], Bytes::new())),
+                // matching topic, but missing the indexed addresses
+                log(LogData::new_unchecked(
+                    vec![<Transfer as alloy_sol_types::SolEvent>::SIGNATURE_HASH],
+                    Bytes::new(),
+                )),
+            ],
+        );
+
+        let events = receipt.decode_events::<Transfer>();
+        assert_eq!(events.len(), 2);
+        let decoded = events[0].as_ref().unwrap();
+        assert_eq!((decoded.from, decoded.to, decoded.value), (from, to, transfer.value));
+        assert!(events[1].is_err());
+
+        let approvals = receipt.decode_events::<Approval>();
+        assert_eq!(approvals.len(), 1);
+        assert_eq!(approvals[0].as_ref().unwrap().value, alloy_primitives::U256::MAX);
+    }
+
+    #[test]
+    fn encode_light() {
+        let receipt = Receipt {
+            status: false.into(),
+            cumulative_gas_used: 84_000,
+            logs: vec![
+                Log::new_unchecked(
+                    address!("0000000000000000000000000000000000000011"),
+                    vec![B256::repeat_byte(1), B256::repeat_byte(2)],
+                    bytes!("0100ff"),
+                ),
+                Log::new_unchecked(
+                    address!("0000000000000000000000000000000000000022"),
+                    vec![],
+                    bytes!(""),
+                ),
+            ],
+        };
+
+        let mut light = Vec::new();
+        receipt.encode_light(&mut light);
+        assert_eq!(light.len(), receipt.encoded_len_light());
+
+        // the 256 byte bloom with a 3 byte header becomes a 32 byte hash with a 1 byte header
+        let consensus = alloy_rlp::encode(receipt.clone().with_bloom());
+        let consensus_header = alloy_rlp::Header::decode(&mut consensus.as_slice()).unwrap();
+        let light_header = alloy_rlp::Header::decode(&mut light.as_slice()).unwrap();
+        assert_eq!(
+            consensus_header.payload_length - light_header.payload_length,
+            (256 + 3) - (32 + 1)
+        );
+        assert!(light.len() < consensus.len());
+
+        let mut buf = light.as_slice();
+        assert_eq!(Receipt::<Log>::decode_light(&mut buf).unwrap(), receipt);
+        assert!(buf.is_empty());
+
+        // the bloom hash must match the logs
+        let tampered = Receipt::<Log> { logs: vec![], ..receipt.clone() };
+        let mut out = Vec::new();
+        alloy_rlp::Header { list: true, payload_length: tampered.payload_len_light() }
+            .encode(&mut out);
+        tampered.status.encode(&mut out);
+        tampered.cumulative_gas_used.encode(&mut out);
+        keccak256(receipt.bloom_slow()).encode(&mut out);
+        tampered.logs.encode(&mut out);
+        assert_eq!(
+            Receipt::<Log>::decode_light(&mut out.as_slice()),
+            Err(alloy_rlp::Error::Custom("light receipt bloom hash mismatch"))
+        );
+    }
+
+    #[test]
+    fn content_id() {
+        let receipt = Receipt {
+            status: true.into(),
+            cumulative_gas_used: 21_000,
+            logs: vec![Log::new_unchecked(
+                address!("0000000000000000000000000000000000000011"),
+                vec![B256::repeat_byte(1)],
+                bytes!("0100ff"),
+            )],
+        };
+        let mut out = Vec::new();
+        receipt.encode_without_bloom(&mut out);
+        assert_eq!(receipt.content_id(), keccak256(out));
+
+        let with_bloom = receipt.clone().with_bloom();
+        let stale_bloom = ReceiptWithBloom::new(receipt.clone(), Bloom::default());
+        assert_ne!(with_bloom, stale_bloom);
+        assert_eq!(with_bloom.receipt.content_id(), stale_bloom.receipt.content_id());
+        assert_eq!(
+            LazyBloomReceipt::from(receipt.clone()).receipt().content_id(),
+            receipt.content_id()
+        );
+
+        let other = Receipt { cumulative_gas_used: 21_001, ..receipt.clone() };
+        assert_ne!(other.content_id(), receipt.content_id());
+        let failed = Receipt { status: false.into(), ..receipt.clone() };
+        assert_ne!(failed.content_id(), receipt.content_id());
+    }
+
+    #[test]
+    fn cached_receipt_length() {
+        let log = Log {
+            address: address!("0000000000000000000000000000000000000011"),
+            data: LogData::new_unchecked(
+                vec![b256!("000000000000000000000000000000000000000000000000000000000000dead")],
+                bytes!("0100ff"),
+            ),
+        };
+        let receipt =
+            Receipt { status: true.into(), cumulative_gas_used: 1, logs: vec![log.clone()] };
+        let mut cached = CachedReceipt::new(receipt.with_bloom());
+        assert_eq!(cached.cached_payload_length(), None);
+
+        assert_eq!(cached.length(), cached.receipt().length());
+        assert!(cached.cached_payload_length().is_some());
+        assert_eq!(alloy_rlp::encode(&cached), alloy_rlp::encode(cached.receipt()));
+
+        // mutating the receipt invalidates the cached length
+        cached.receipt_mut().receipt.logs.push(log);
+        assert_eq!(cached.cached_payload_length(), None);
+        assert_eq!(cached.length(), cached.receipt().length());
+
+        let encoded = alloy_rlp::encode(&cached);
+        assert_eq!(encoded.len(), cached.length());
+        assert_eq!(CachedReceipt::decode(&mut encoded.as_slice()).unwrap(), cached);
+    }
+
+    #[test]
+    fn post_state_root() {
+        let root = b256!("2a3f9c5e6b1d7f8e0a4c2b6d8e1f3a5c7b9d0e2f4a6c8b1d3e5f7a9c0b2d4e6f");
+        let receipt = Receipt::<Log> { status: root.into(), ..Default::default() };
+        assert_eq!(receipt.post_state_root(), Some(root));
+
+        let receipt = Receipt::<Log> { status: true.into(), ..Default::default() };
+        assert_eq!(receipt.post_state_root(), None);
+    }
+
+    #[test]
+    fn receipt_diff() {
+        let receipt = Receipt {
+            status: true.into(),
+            cumulative_gas_used: 21000,
+            logs: vec![Log::<LogData>::default()],
+        }
+        .with_bloom();
+        assert!(receipt.diff(&receipt).is_empty());
+
+        let other = ReceiptWithBloom { logs_bloom: Bloom::repeat_byte(0xff), ..receipt.clone() };
+        let diff = receipt.diff(&other);
+        assert_eq!(diff, ReceiptDiff { logs_bloom: true, ..Default::default() });
+        assert!(!diff.is_empty());
+        assert!(diff.is_bloom_only());
+
+        let other = ReceiptWithBloom {
+            receipt: Receipt {
+                cumulative_gas_used: 42000,
+                logs: vec![],
+                ..receipt.receipt.clone()
+            },
+            ..other
+        };
+        let diff = receipt.diff(&other);
+        assert_eq!(
+            diff,
+            ReceiptDiff { status: false, cumulative_gas_used: true, logs: true, logs_bloom: true }
+        );
+        assert!(!diff.is_bloom_only());
+    }
+
+    #[test]
+    fn receipt_rlp_iter() {
+        let receipts: Vec<_> = (1..=3)
+            .map(|i| {
+                Receipt {
+                    status: true.into(),
+                    cumulative_gas_used: i * 21000,
+                    logs: vec![Log::<LogData>::default(); i as usize],
+                }
+                .with_bloom()
+            })
+            .collect();
+        let mut buf = Vec::new();
+        for receipt in &receipts {
+            receipt.encode(&mut buf);
+        }
+
+        let decoded = ReceiptRlpIter::new(&buf).collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(decoded, receipts);
+
+        // a truncated buffer yields the receipts before the error, then the error, then stops
+        let mut iter = ReceiptRlpIter::<Log>::new(&buf[..buf.len() - 1]);
+        assert_eq!(iter.next(), Some(Ok(receipts[0].clone())));
+        assert_eq!(iter.next(), Some(Ok(receipts[1].clone())));
+        assert!(matches!(iter.next(), Some(Err(_))));
+        assert_eq!(iter.next(), None);
+        assert!(iter.remaining().is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn from_rpc_lenient() {
+        let rpc = serde_json::json!({
+            "transactionHash": "0x1fa9d8a5a4fda4e9e27a17a4bd1a4d4d8ac0b6cd0c6ef12fe96de5b5e83d9c6c",
+            "transactionIndex": "0x0",
+            "effectiveGasPrice": "0x3b9aca00",
+            "gasUsed": "0x5208",
+            "type": "0x2",
+            "status": "0x1",
+            "cumulativeGasUsed": "0x5208",
+            "logs": [{
+                "address": "0x0000000000000000000000000000000000000011",
+                "topics": ["0x000000000000000000000000000000000000000000000000000000000000dead"],
+                "data": "0x0100ff",
+                "logIndex": "0x0",
+                "removed": false
+            }]
+        });
+
+        let receipt = Receipt::<Log>::from_rpc_lenient(rpc.clone()).unwrap();
+        assert_eq!(
+            receipt,
+            Receipt {
+                status: true.into(),
+                cumulative_gas_used: 21_000,
+                logs: vec![Log {
+                    address: address!("0000000000000000000000000000000000000011"),
+                    data: LogData::new_unchecked(
+                        vec![b256!(
+                            "000000000000000000000000000000000000000000000000000000000000dead"
+                        )],
+                        bytes!("0100ff"),
+                    ),
+                }],
+            }
+        );
+        assert_eq!(serde_json::from_value::<Receipt>(rpc).unwrap(), receipt);
+
+        assert!(Receipt::<Log>::from_rpc_lenient(serde_json::json!([])).is_err());
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn root_vs_status() {
@@ -1,5 +1,7 @@
-use crate::transaction::SignableTransaction;
+use crate::{transaction::SignableTransaction, TxEip1559, TxEip2930, TxEip4844, TxLegacy, TxType};
 use alloy_primitives::{Signature, B256};
+#[cfg(feature = "k256")]
+use core::fmt;
 
 /// A transaction with a signature and hash seal.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -64,4 +66,143 @@ impl<T: SignableTransaction<Signature>> Signed<T, Signature> {
         let sighash = self.tx.signature_hash();
         self.signature.recover_address_from_prehash(&sighash)
     }
+
+    /// Recover the signer of the transaction, rejecting signatures that recover to
+    /// [`Address::ZERO`](alloy_primitives::Address::ZERO).
+    ///
+    /// No private key is known for the zero address, so such a transaction can only originate
+    /// from a malformed signature. This is useful when decoding transactions from untrusted
+    /// sources.
+    ///
+    /// Returns [`RecoverSignerError::ZeroAddress`] for such signatures, and
+    /// [`RecoverSignerError::Signature`] if recovery itself fails.
+    pub fn recover_signer_nonzero(&self) -> Result<alloy_primitives::Address, RecoverSignerError> {
+        reject_zero_signer(self.recover_signer()?)
+    }
+
+    /// Returns `true` if the recovered signer is also the recipient of the transaction.
+    ///
+    /// Contract creations are never self-transfers.
+    pub fn is_self_transfer(&self) -> Result<bool, alloy_primitives::SignatureError> {
+        let Some(to) = self.tx.to().to().copied() else { return Ok(false) };
+        Ok(self.recover_signer()? == to)
+    }
+}
+
+/// Error returned by [`Signed::recover_signer_nonzero`].
+#[cfg(feature = "k256")]
+#[derive(Debug)]
+pub enum RecoverSignerError {
+    /// The signer could not be recovered from the signature.
+    Signature(alloy_primitives::SignatureError),
+    /// The signature recovers to [`Address::ZERO`](alloy_primitives::Address::ZERO).
+    ZeroAddress,
+}
+
+#[cfg(feature = "k256")]
+impl From<alloy_primitives::SignatureError> for RecoverSignerError {
+    fn from(err: alloy_primitives::SignatureError) -> Self {
+        Self::Signature(err)
+    }
+}
+
+#[cfg(feature = "k256")]
+impl fmt::Display for RecoverSignerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Signature(err) => err.fmt(f),
+            Self::ZeroAddress => f.write_str("signature recovers to the zero address"),
+        }
+    }
+}
+
+#[cfg(all(feature = "k256", feature = "std"))]
+impl std::error::Error for RecoverSignerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Signature(err) => Some(err),
+            Self::ZeroAddress => None,
+        }
+    }
+}
+
+/// Rejects the zero address as a recovered signer, see [`Signed::recover_signer_nonzero`].
+#[cfg(feature = "k256")]
+fn reject_zero_signer(
+    signer: alloy_primitives::Address,
+) -> Result<alloy_primitives::Address, RecoverSignerError> {
+    if signer.is_zero() {
+        return Err(RecoverSignerError::ZeroAddress);
+    }
+    Ok(signer)
+}
+
+/// A compact announcement of a signed transaction, announced to peers before sending the full
+/// transaction, like the entries of the devp2p `NewPooledTransactionHashes` message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TxAnnouncement {
+    /// The type of the transaction.
+    pub ty: TxType,
+    /// The hash of the transaction.
+    pub hash: B256,
+    /// The size of the transaction.
+    pub size: usize,
+}
+
+macro_rules! impl_announcement {
+    ($($tx:ident => $ty:ident),* $(,)?) => {$(
+        impl<Sig> Signed<$tx, Sig> {
+            /// Returns the announcement of the transaction, using its cached hash.
+            ///
+            #[doc = concat!("The size is the in-memory heuristic of [`", stringify!($tx), "::size`],")]
+            /// not the length of the network encoding.
+            pub fn announcement(&self) -> TxAnnouncement {
+                TxAnnouncement { ty: TxType::$ty, hash: self.hash, size: self.tx.size() }
+            }
+        }
+    )*};
+}
+
+impl_announcement!(
+    TxLegacy => Legacy,
+    TxEip2930 => Eip2930,
+    TxEip1559 => Eip1559,
+    TxEip4844 => Eip4844,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, U256};
+
+    #[cfg(feature = "k256")]
+    #[test]
+    fn reject_zero_signer() {
+        let signer = address!("3535353535353535353535353535353535353535");
+        assert_eq!(super::reject_zero_signer(signer).unwrap(), signer);
+        assert!(matches!(
+            super::reject_zero_signer(alloy_primitives::Address::ZERO),
+            Err(RecoverSignerError::ZeroAddress)
+        ));
+    }
+
+    #[test]
+    fn announcement() {
+        let tx = TxLegacy {
+            chain_id: Some(1),
+            nonce: 3,
+            gas_price: 20_000_000_000,
+            gas_limit: 21_000,
+            to: address!("3535353535353535353535353535353535353535").into(),
+            value: U256::from(1_000_000_000_000_000_000u128),
+            input: vec![1, 2, 3].into(),
+        };
+        let size = tx.size();
+        let signed = tx.into_signed(Signature::test_signature());
+
+        assert_eq!(
+            signed.announcement(),
+            TxAnnouncement { ty: TxType::Legacy, hash: *signed.hash(), size }
+        );
+    }
 }
@@ -0,0 +1,398 @@
+use crate::{TxEip1559, TxEip2930, TxLegacy};
+use alloy_eips::eip2930::AccessList;
+use alloy_primitives::{Bytes, ChainId, TxKind, U256};
+use core::fmt;
+
+/// Builder for a [`TxLegacy`].
+///
+/// The gas price and the gas limit are required. Unset optional fields default to no chain ID
+/// (a pre-[EIP-155] transaction), a zero nonce and value, a contract creation and an empty
+/// input.
+///
+/// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[must_use = "builders do nothing unless `build` is called"]
+pub struct TxLegacyBuilder {
+    chain_id: Option<ChainId>,
+    nonce: u64,
+    gas_price: Option<u128>,
+    gas_limit: Option<u128>,
+    to: TxKind,
+    value: U256,
+    input: Bytes,
+}
+
+impl TxLegacyBuilder {
+    /// Sets the [EIP-155] chain ID.
+    ///
+    /// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+    pub const fn chain_id(mut self, chain_id: ChainId) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    /// Sets the nonce.
+    pub const fn nonce(mut self, nonce: u64) -> Self {
+        self.nonce = nonce;
+        self
+    }
+
+    /// Sets the gas price.
+    pub const fn gas_price(mut self, gas_price: u128) -> Self {
+        self.gas_price = Some(gas_price);
+        self
+    }
+
+    /// Sets the gas limit.
+    pub const fn gas_limit(mut self, gas_limit: u128) -> Self {
+        self.gas_limit = Some(gas_limit);
+        self
+    }
+
+    /// Sets the recipient, or [`TxKind::Create`] for a contract creation.
+    pub fn to(mut self, to: impl Into<TxKind>) -> Self {
+        self.to = to.into();
+        self
+    }
+
+    /// Sets the value.
+    pub const fn value(mut self, value: U256) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Sets the calldata, or the init code for a contract creation.
+    pub fn input(mut self, input: impl Into<Bytes>) -> Self {
+        self.input = input.into();
+        self
+    }
+
+    /// Builds the transaction.
+    ///
+    /// Returns an error if the gas price or the gas limit is not set.
+    pub fn build(self) -> Result<TxLegacy, TxBuildError> {
+        Ok(TxLegacy {
+            chain_id: self.chain_id,
+            nonce: self.nonce,
+            gas_price: self.gas_price.ok_or(TxBuildError::MissingField("gas_price"))?,
+            gas_limit: self.gas_limit.ok_or(TxBuildError::MissingField("gas_limit"))?,
+            to: self.to,
+            value: self.value,
+            input: self.input,
+        })
+    }
+}
+
+/// Builder for a [`TxEip2930`].
+///
+/// The chain ID, the gas price and the gas limit are required. Unset optional fields default to
+/// a zero nonce and value, a contract creation, an empty access list and an empty input.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[must_use = "builders do nothing unless `build` is called"]
+pub struct TxEip2930Builder {
+    chain_id: Option<ChainId>,
+    nonce: u64,
+    gas_price: Option<u128>,
+    gas_limit: Option<u128>,
+    to: TxKind,
+    value: U256,
+    access_list: AccessList,
+    input: Bytes,
+}
+
+impl TxEip2930Builder {
+    /// Sets the chain ID.
+    pub const fn chain_id(mut self, chain_id: ChainId) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    /// Sets the nonce.
+    pub const fn nonce(mut self, nonce: u64) -> Self {
+        self.nonce = nonce;
+        self
+    }
+
+    /// Sets the gas price.
+    pub const fn gas_price(mut self, gas_price: u128) -> Self {
+        self.gas_price = Some(gas_price);
+        self
+    }
+
+    /// Sets the gas limit.
+    pub const fn gas_limit(mut self, gas_limit: u128) -> Self {
+        self.gas_limit = Some(gas_limit);
+        self
+    }
+
+    /// Sets the recipient, or [`TxKind::Create`] for a contract creation.
+    pub fn to(mut self, to: impl Into<TxKind>) -> Self {
+        self.to = to.into();
+        self
+    }
+
+    /// Sets the value.
+    pub const fn value(mut self, value: U256) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Sets the access list.
+    pub fn access_list(mut self, access_list: AccessList) -> Self {
+        self.access_list = access_list;
+        self
+    }
+
+    /// Sets the calldata, or the init code for a contract creation.
+    pub fn input(mut self, input: impl Into<Bytes>) -> Self {
+        self.input = input.into();
+        self
+    }
+
+    /// Builds the transaction.
+    ///
+    /// Returns an error if the chain ID, the gas price or the gas limit is not set.
+    pub fn build(self) -> Result<TxEip2930, TxBuildError> {
+        Ok(TxEip2930 {
+            chain_id: self.chain_id.ok_or(TxBuildError::MissingField("chain_id"))?,
+            nonce: self.nonce,
+            gas_price: self.gas_price.ok_or(TxBuildError::MissingField("gas_price"))?,
+            gas_limit: self.gas_limit.ok_or(TxBuildError::MissingField("gas_limit"))?,
+            to: self.to,
+            value: self.value,
+            access_list: self.access_list,
+            input: self.input,
+        })
+    }
+}
+
+/// Builder for a [`TxEip1559`].
+///
+/// The chain ID, both fee fields and the gas limit are required. Unset optional fields default
+/// to a zero nonce and value, a contract creation, an empty access list and an empty input.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[must_use = "builders do nothing unless `build` is called"]
+pub struct TxEip1559Builder {
+    chain_id: Option<ChainId>,
+    nonce: u64,
+    gas_limit: Option<u128>,
+    max_fee_per_gas: Option<u128>,
+    max_priority_fee_per_gas: Option<u128>,
+    to: TxKind,
+    value: U256,
+    access_list: AccessList,
+    input: Bytes,
+}
+
+impl TxEip1559Builder {
+    /// Sets the chain ID.
+    pub const fn chain_id(mut self, chain_id: ChainId) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    /// Sets the nonce.
+    pub const fn nonce(mut self, nonce: u64) -> Self {
+        self.nonce = nonce;
+        self
+    }
+
+    /// Sets the gas limit.
+    pub const fn gas_limit(mut self, gas_limit: u128) -> Self {
+        self.gas_limit = Some(gas_limit);
+        self
+    }
+
+    /// Sets the max fee per gas.
+    pub const fn max_fee_per_gas(mut self, max_fee_per_gas: u128) -> Self {
+        self.max_fee_per_gas = Some(max_fee_per_gas);
+        self
+    }
+
+    /// Sets the max priority fee per gas.
+    pub const fn max_priority_fee_per_gas(mut self, max_priority_fee_per_gas: u128) -> Self {
+        self.max_priority_fee_per_gas = Some(max_priority_fee_per_gas);
+        self
+    }
+
+    /// Sets the recipient, or [`TxKind::Create`] for a contract creation.
+    pub fn to(mut self, to: impl Into<TxKind>) -> Self {
+        self.to = to.into();
+        self
+    }
+
+    /// Sets the value.
+    pub const fn value(mut self, value: U256) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Sets the access list.
+    pub fn access_list(mut self, access_list: AccessList) -> Self {
+        self.access_list = access_list;
+        self
+    }
+
+    /// Sets the calldata, or the init code for a contract creation.
+    pub fn input(mut self, input: impl Into<Bytes>) -> Self {
+        self.input = input.into();
+        self
+    }
+
+    /// Builds the transaction.
+    ///
+    /// Returns an error if the chain ID, a fee field or the gas limit is not set, or if the max
+    /// priority fee per gas exceeds the max fee per gas.
+    pub fn build(self) -> Result<TxEip1559, TxBuildError> {
+        let max_fee_per_gas =
+            self.max_fee_per_gas.ok_or(TxBuildError::MissingField("max_fee_per_gas"))?;
+        let max_priority_fee_per_gas = self
+            .max_priority_fee_per_gas
+            .ok_or(TxBuildError::MissingField("max_priority_fee_per_gas"))?;
+        if max_priority_fee_per_gas > max_fee_per_gas {
+            return Err(TxBuildError::PriorityFeeExceedsMaxFee {
+                max_priority_fee_per_gas,
+                max_fee_per_gas,
+            });
+        }
+        Ok(TxEip1559 {
+            chain_id: self.chain_id.ok_or(TxBuildError::MissingField("chain_id"))?,
+            nonce: self.nonce,
+            gas_limit: self.gas_limit.ok_or(TxBuildError::MissingField("gas_limit"))?,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            to: self.to,
+            value: self.value,
+            access_list: self.access_list,
+            input: self.input,
+        })
+    }
+}
+
+/// Error returned when building a transaction with e.g. [`TxLegacyBuilder::build`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TxBuildError {
+    /// A required field is not set.
+    MissingField(&'static str),
+    /// The max priority fee per gas is higher than the max fee per gas.
+    PriorityFeeExceedsMaxFee {
+        /// The max priority fee per gas of the transaction.
+        max_priority_fee_per_gas: u128,
+        /// The max fee per gas of the transaction.
+        max_fee_per_gas: u128,
+    },
+}
+
+impl fmt::Display for TxBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingField(field) => write!(f, "missing required field `{field}`"),
+            Self::PriorityFeeExceedsMaxFee { max_priority_fee_per_gas, max_fee_per_gas } => write!(
+                f,
+                "max priority fee per gas {max_priority_fee_per_gas} exceeds max fee per gas \
+                 {max_fee_per_gas}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TxBuildError {}
+
+impl TxLegacy {
+    /// Returns a [`TxLegacyBuilder`].
+    pub fn builder() -> TxLegacyBuilder {
+        TxLegacyBuilder::default()
+    }
+}
+
+impl TxEip2930 {
+    /// Returns a [`TxEip2930Builder`].
+    pub fn builder() -> TxEip2930Builder {
+        TxEip2930Builder::default()
+    }
+}
+
+impl TxEip1559 {
+    /// Returns a [`TxEip1559Builder`].
+    pub fn builder() -> TxEip1559Builder {
+        TxEip1559Builder::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, bytes};
+
+    #[test]
+    fn build_legacy() {
+        let to = address!("6069a6c32cf691f5982febae4faf8a6f3ab2f0f6");
+        let tx = TxLegacy::builder()
+            .to(to)
+            .value(U256::from(1))
+            .gas_limit(21_000)
+            .gas_price(20_000_000_000)
+            .build()
+            .unwrap();
+        assert_eq!(
+            tx,
+            TxLegacy {
+                chain_id: None,
+                nonce: 0,
+                gas_price: 20_000_000_000,
+                gas_limit: 21_000,
+                to: to.into(),
+                value: U256::from(1),
+                input: Bytes::new(),
+            }
+        );
+
+        let tx = TxLegacy::builder().chain_id(1).nonce(7).gas_price(1).gas_limit(53_000);
+        assert_eq!(tx.clone().build().unwrap().to, TxKind::Create);
+        assert_eq!(tx.input(bytes!("6000")).build().unwrap().input, bytes!("6000"));
+
+        let missing = TxLegacy::builder().to(to).gas_limit(21_000).build();
+        assert_eq!(missing, Err(TxBuildError::MissingField("gas_price")));
+        assert_eq!(missing.unwrap_err().to_string(), "missing required field `gas_price`");
+        assert_eq!(
+            TxLegacy::builder().gas_price(1).build(),
+            Err(TxBuildError::MissingField("gas_limit"))
+        );
+    }
+
+    #[test]
+    fn build_eip2930() {
+        let tx = TxEip2930::builder().chain_id(1).gas_price(1).gas_limit(21_000).build().unwrap();
+        assert_eq!(
+            tx,
+            TxEip2930 { chain_id: 1, gas_price: 1, gas_limit: 21_000, ..Default::default() }
+        );
+        assert_eq!(
+            TxEip2930::builder().gas_price(1).gas_limit(21_000).build(),
+            Err(TxBuildError::MissingField("chain_id"))
+        );
+    }
+
+    #[test]
+    fn build_eip1559() {
+        let builder = TxEip1559::builder().chain_id(1).gas_limit(21_000).max_fee_per_gas(10);
+        assert_eq!(
+            builder.clone().build(),
+            Err(TxBuildError::MissingField("max_priority_fee_per_gas"))
+        );
+
+        let tx = builder.clone().max_priority_fee_per_gas(2).build().unwrap();
+        assert_eq!(tx.max_fee_per_gas, 10);
+        assert_eq!(tx.max_priority_fee_per_gas, 2);
+        assert_eq!(tx.access_list, AccessList::default());
+
+        assert_eq!(
+            builder.max_priority_fee_per_gas(11).build(),
+            Err(TxBuildError::PriorityFeeExceedsMaxFee {
+                max_priority_fee_per_gas: 11,
+                max_fee_per_gas: 10
+            })
+        );
+    }
+}
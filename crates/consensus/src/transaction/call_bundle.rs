@@ -0,0 +1,122 @@
+use alloy_primitives::{Address, Bytes, U256};
+
+/// A transaction call in the shape expected by bundle simulation APIs such as
+/// `eth_callBundle`.
+///
+/// All numeric fields are serialized as hex quantities. Legacy and [EIP-2930] transactions set
+/// [`gas_price`](Self::gas_price), while [EIP-1559] and [EIP-4844] transactions set
+/// [`max_fee_per_gas`](Self::max_fee_per_gas) and
+/// [`max_priority_fee_per_gas`](Self::max_priority_fee_per_gas).
+///
+/// See [`Transaction::to_call_bundle_entry`](crate::Transaction::to_call_bundle_entry).
+///
+/// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+/// [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+/// [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct CallBundleEntry {
+    /// The sender of the call.
+    pub from: Address,
+    /// The recipient of the call, or `None` for a contract creation.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub to: Option<Address>,
+    /// The gas limit of the call.
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub gas: u128,
+    /// The value transferred by the call.
+    pub value: U256,
+    /// The calldata, or init code for a contract creation.
+    pub data: Bytes,
+    /// The gas price of a legacy or [EIP-2930] transaction.
+    ///
+    /// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            with = "alloy_serde::quantity::opt",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
+    pub gas_price: Option<u128>,
+    /// The max fee per gas of an [EIP-1559] transaction.
+    ///
+    /// [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            with = "alloy_serde::quantity::opt",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
+    pub max_fee_per_gas: Option<u128>,
+    /// The max priority fee per gas of an [EIP-1559] transaction.
+    ///
+    /// [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            with = "alloy_serde::quantity::opt",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
+    pub max_priority_fee_per_gas: Option<u128>,
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use crate::{Transaction, TxEip1559, TxLegacy};
+    use alloy_primitives::{address, hex, U256};
+
+    #[test]
+    fn serializes_eip1559_entry() {
+        let from = address!("dd6b8b3dc6b7ad97db52f08a275ff4483e024cea");
+        let tx = TxEip1559 {
+            chain_id: 1,
+            nonce: 0x42,
+            gas_limit: 44386,
+            to: address!("6069a6c32cf691f5982febae4faf8a6f3ab2f0f6").into(),
+            value: U256::from(1),
+            input: hex!("a22cb465").into(),
+            max_fee_per_gas: 0x4a817c800,
+            max_priority_fee_per_gas: 0x3b9aca00,
+            ..Default::default()
+        };
+
+        let entry = tx.to_call_bundle_entry(from);
+        let json = serde_json::to_value(&entry).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "from": "0xdd6b8b3dc6b7ad97db52f08a275ff4483e024cea",
+                "to": "0x6069a6c32cf691f5982febae4faf8a6f3ab2f0f6",
+                "gas": "0xad62",
+                "value": "0x1",
+                "data": "0xa22cb465",
+                "maxFeePerGas": "0x4a817c800",
+                "maxPriorityFeePerGas": "0x3b9aca00"
+            })
+        );
+        assert_eq!(serde_json::from_value::<super::CallBundleEntry>(json).unwrap(), entry);
+    }
+
+    #[test]
+    fn serializes_legacy_creation_entry() {
+        let tx = TxLegacy { gas_price: 7, gas_limit: 53000, ..Default::default() };
+        let json = serde_json::to_value(tx.to_call_bundle_entry(Default::default())).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "from": "0x0000000000000000000000000000000000000000",
+                "gas": "0xcf08",
+                "value": "0x0",
+                "data": "0x",
+                "gasPrice": "0x7"
+            })
+        );
+    }
+}
@@ -1,6 +1,8 @@
-use crate::{SignableTransaction, Signed, Transaction, TxType};
+use crate::{
+    AccessListUnsupported, FeeMarketUnsupported, SignableTransaction, Signed, Transaction, TxType,
+};
 use alloy_eips::eip2930::AccessList;
-use alloy_primitives::{keccak256, Bytes, ChainId, Signature, TxKind, U256};
+use alloy_primitives::{keccak256, Bytes, ChainId, Signature, TxKind, B256, U256};
 use alloy_rlp::{BufMut, Decodable, Encodable, Header};
 use core::mem;
 
@@ -147,6 +149,13 @@ impl TxEip1559 {
         self.access_list.encode(out);
     }
 
+    /// Returns the RLP encoding of the transaction's fields, without a RLP header.
+    pub fn encode_fields_to_bytes(&self) -> Bytes {
+        let mut out = Vec::with_capacity(self.fields_len());
+        self.encode_fields(&mut out);
+        out.into()
+    }
+
     /// Returns what the encoded length should be, if the transaction were RLP encoded with the
     /// given signature, depending on the value of `with_header`.
     ///
@@ -258,6 +267,56 @@ impl TxEip1559 {
         self.access_list.size() + // access_list
         self.input.len() // input
     }
+
+    /// Returns the transaction with its gas limit increased by `percent`, e.g. to add a safety
+    /// margin on top of a gas estimate.
+    ///
+    /// The result is rounded down and saturates at `u128::MAX` instead of overflowing.
+    pub const fn with_gas_margin(mut self, percent: u16) -> Self {
+        self.gas_limit = super::gas_limit_with_margin(self.gas_limit, percent);
+        self
+    }
+
+    /// Returns the transaction with its gas limit lowered to `max` if it exceeds it, e.g. to cap
+    /// it at the block gas limit.
+    pub const fn clamp_gas_limit(mut self, max: u64) -> Self {
+        if self.gas_limit > max as u128 {
+            self.gas_limit = max as u128;
+        }
+        self
+    }
+
+    /// Increases the fees of the transaction by at least `percent`, e.g. to replace a pending
+    /// transaction with the same nonce. Most nodes require a bump of at least 10%.
+    ///
+    /// The fees are rounded up and saturate at `u128::MAX` instead of overflowing.
+    pub fn bump_fees(&mut self, percent: u16) {
+        self.max_fee_per_gas = super::fee_with_bump(self.max_fee_per_gas, percent);
+        self.max_priority_fee_per_gas =
+            super::fee_with_bump(self.max_priority_fee_per_gas, percent);
+    }
+}
+
+impl Signed<TxEip1559> {
+    /// Returns the [EIP-2718] encoding of the signed transaction: `tx_type || rlp(payload)`.
+    ///
+    /// [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+    pub fn encoded_2718(&self) -> Bytes {
+        let mut out = Vec::new();
+        self.tx().encode_with_signature(self.signature(), &mut out, false);
+        out.into()
+    }
+
+    /// Returns the network encoding of the signed transaction, as used by the p2p protocol.
+    ///
+    /// Unlike [`encoded_2718`](Self::encoded_2718), this wraps the encoding in an RLP string
+    /// header: `rlp(tx_type || rlp(payload))`. This is the form in which typed transactions
+    /// appear in RLP lists, e.g. block bodies.
+    pub fn network_encoded(&self) -> Bytes {
+        let mut out = Vec::new();
+        self.tx().encode_with_signature(self.signature(), &mut out, true);
+        out.into()
+    }
 }
 
 impl Transaction for TxEip1559 {
@@ -288,6 +347,30 @@ impl Transaction for TxEip1559 {
     fn input(&self) -> &[u8] {
         &self.input
     }
+
+    fn max_fee_per_gas(&self) -> u128 {
+        self.max_fee_per_gas
+    }
+
+    fn max_priority_fee_per_gas(&self) -> Option<u128> {
+        Some(self.max_priority_fee_per_gas)
+    }
+
+    fn max_fee_per_blob_gas(&self) -> Option<u128> {
+        None
+    }
+
+    fn ty(&self) -> u8 {
+        TxType::Eip1559 as u8
+    }
+
+    fn access_list(&self) -> Option<&AccessList> {
+        Some(&self.access_list)
+    }
+
+    fn blob_versioned_hashes(&self) -> Option<&[B256]> {
+        None
+    }
 }
 
 impl SignableTransaction<Signature> for TxEip1559 {
@@ -295,6 +378,28 @@ impl SignableTransaction<Signature> for TxEip1559 {
         self.chain_id = chain_id;
     }
 
+    fn with_access_list(
+        mut self,
+        access_list: AccessList,
+    ) -> Result<Self, AccessListUnsupported<Self>> {
+        self.access_list = access_list;
+        Ok(self)
+    }
+
+    fn apply_fee_suggestion(
+        mut self,
+        max_fee_per_gas: u128,
+        max_priority_fee_per_gas: u128,
+    ) -> Result<Self, FeeMarketUnsupported<Self>> {
+        self.max_fee_per_gas = max_fee_per_gas;
+        self.max_priority_fee_per_gas = max_priority_fee_per_gas;
+        Ok(self)
+    }
+
+    fn bump_fees(&mut self, percent: u16) {
+        self.bump_fees(percent);
+    }
+
     fn encode_for_signing(&self, out: &mut dyn alloy_rlp::BufMut) {
         out.put_u8(self.tx_type() as u8);
         self.encode(out)
@@ -304,6 +409,10 @@ impl SignableTransaction<Signature> for TxEip1559 {
         self.length() + 1
     }
 
+    fn unsigned_fingerprint(&self) -> B256 {
+        keccak256(self.encode_fields_to_bytes())
+    }
+
     fn into_signed(self, signature: Signature) -> Signed<Self> {
         let mut buf = Vec::with_capacity(self.encoded_len_with_signature(&signature, false));
         self.encode_with_signature(&signature, &mut buf, false);
@@ -346,7 +455,7 @@ mod tests {
     use super::TxEip1559;
     use crate::SignableTransaction;
     use alloy_eips::eip2930::AccessList;
-    use alloy_primitives::{address, b256, hex, Address, Signature, B256, U256};
+    use alloy_primitives::{address, b256, hex, Address, Signature, TxKind, B256, U256};
 
     #[test]
     fn recover_signer_eip1559() {
@@ -380,6 +489,7 @@ mod tests {
         let signed_tx = tx.into_signed(sig);
         assert_eq!(*signed_tx.hash(), hash, "Expected same hash");
         assert_eq!(signed_tx.recover_signer().unwrap(), signer, "Recovering signer should pass.");
+        assert_eq!(signed_tx.recover_signer_nonzero().unwrap(), signer);
     }
 
     #[test]
@@ -411,4 +521,174 @@ mod tests {
         assert_eq!(decoded, tx.into_signed(sig));
         assert_eq!(*decoded.hash(), hash);
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn eip1559_to_request_json() {
+        use crate::Transaction;
+
+        let tx = TxEip1559 {
+            chain_id: 1,
+            nonce: 0x42,
+            gas_limit: 44386,
+            to: address!("6069a6c32cf691f5982febae4faf8a6f3ab2f0f6").into(),
+            value: U256::from(0_u64),
+            input: hex!("a22cb465").into(),
+            max_fee_per_gas: 0x4a817c800,
+            max_priority_fee_per_gas: 0x3b9aca00,
+            access_list: AccessList::default(),
+        };
+
+        let expected: serde_json::Value = serde_json::from_str(
+            r#"{
+                "to": "0x6069a6C32cf691F5982FEbAe4fAf8a6f3AB2F0F6",
+                "gas": "0xad62",
+                "maxFeePerGas": "0x4a817c800",
+                "maxPriorityFeePerGas": "0x3b9aca00",
+                "value": "0x0",
+                "input": "0xa22cb465",
+                "nonce": "0x42",
+                "chainId": "0x1",
+                "accessList": [],
+                "type": "0x2"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(tx.to_request_json(None), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn eip1559_to_display_json() {
+        use crate::Transaction;
+
+        let tx = TxEip1559 {
+            chain_id: 1,
+            nonce: 0x42,
+            gas_limit: 44386,
+            to: address!("6069a6c32cf691f5982febae4faf8a6f3ab2f0f6").into(),
+            value: U256::from(1_500_000_000_000_000_000_u128),
+            input: hex!("a22cb46500").into(),
+            max_fee_per_gas: 0x4a817c800,
+            max_priority_fee_per_gas: 0x3b9aca00,
+            access_list: AccessList::default(),
+        };
+
+        let expected = serde_json::json!({
+            "type": 2,
+            "chainId": 1,
+            "nonce": 66,
+            "to": "0x6069a6C32cf691F5982FEbAe4fAf8a6f3AB2F0F6",
+            "valueWei": "1500000000000000000",
+            "valueEther": "1.500000000000000000",
+            "gasLimit": "44386",
+            "maxFeePerGasGwei": "20.000000000",
+            "maxPriorityFeePerGasGwei": "1.000000000",
+            "dataLength": 5,
+            "selector": "0xa22cb465"
+        });
+        assert_eq!(tx.to_display_json(), expected);
+
+        let create = TxEip1559 { to: TxKind::Create, ..tx };
+        assert_eq!(create.to_display_json()["to"], "Contract Creation");
+        assert_eq!(create.to_display_json()["selector"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn encode_fields_to_bytes() {
+        let tx = TxEip1559 {
+            chain_id: 1,
+            nonce: 0x42,
+            gas_limit: 44386,
+            to: address!("6069a6c32cf691f5982febae4faf8a6f3ab2f0f6").into(),
+            value: U256::from(0_u64),
+            input: hex!("a22cb465").into(),
+            max_fee_per_gas: 0x4a817c800,
+            max_priority_fee_per_gas: 0x3b9aca00,
+            access_list: AccessList::default(),
+        };
+
+        let fields = tx.encode_fields_to_bytes();
+        assert_eq!(fields.len(), tx.fields_len());
+        // the signing payload is the type byte and list header followed by the fields
+        assert!(tx.encoded_for_signing().ends_with(&fields));
+    }
+
+    #[test]
+    fn unsigned_fingerprint() {
+        let tx = TxEip1559 {
+            chain_id: 1,
+            nonce: 0x42,
+            gas_limit: 44386,
+            to: address!("6069a6c32cf691f5982febae4faf8a6f3ab2f0f6").into(),
+            value: U256::from(0_u64),
+            input: hex!("a22cb465").into(),
+            max_fee_per_gas: 0x4a817c800,
+            max_priority_fee_per_gas: 0x3b9aca00,
+            access_list: AccessList::default(),
+        };
+        let fingerprint = tx.unsigned_fingerprint();
+        assert_eq!(fingerprint, alloy_primitives::keccak256(tx.encode_fields_to_bytes()));
+        assert_ne!(fingerprint, tx.signature_hash());
+
+        let a = tx.clone().into_signed(Signature::test_signature());
+        let b = tx.clone().into_signed(Signature::test_signature().with_parity(true));
+        assert_ne!(a.hash(), b.hash());
+        assert_eq!(a.tx().unsigned_fingerprint(), b.tx().unsigned_fingerprint());
+
+        let other = TxEip1559 { nonce: 0x43, ..tx };
+        assert_ne!(other.unsigned_fingerprint(), fingerprint);
+    }
+
+    #[test]
+    fn encoded_2718_eip1559() {
+        use crate::TxEnvelope;
+        use alloy_eips::eip2718::Decodable2718;
+        use alloy_rlp::Decodable;
+
+        let tx = TxEip1559 {
+            chain_id: 1,
+            nonce: 0x42,
+            gas_limit: 44386,
+            to: address!("6069a6c32cf691f5982febae4faf8a6f3ab2f0f6").into(),
+            value: U256::from(0_u64),
+            input: hex!("a22cb465").into(),
+            max_fee_per_gas: 0x4a817c800,
+            max_priority_fee_per_gas: 0x3b9aca00,
+            access_list: AccessList::default(),
+        };
+        let signed = tx.into_signed(Signature::test_signature());
+
+        let encoded = signed.encoded_2718();
+        let network = signed.network_encoded();
+        assert_eq!(encoded[0], 0x02);
+        assert_ne!(encoded, network);
+
+        let decoded = TxEnvelope::decode_2718(&mut encoded.as_ref()).unwrap();
+        assert_eq!(decoded.as_eip1559().unwrap().hash(), signed.hash());
+        let decoded = TxEnvelope::decode(&mut network.as_ref()).unwrap();
+        assert_eq!(decoded.as_eip1559().unwrap().hash(), signed.hash());
+    }
+
+    #[test]
+    fn is_self_transfer() {
+        use k256::ecdsa::SigningKey;
+
+        let key = SigningKey::from_slice(&[1; 32]).unwrap();
+        let sender = alloy_signer::utils::secret_key_to_address(&key);
+        let sign = |tx: TxEip1559| {
+            let (sig, recid) =
+                key.sign_prehash_recoverable(tx.signature_hash().as_slice()).unwrap();
+            tx.into_signed(Signature::from_signature_and_parity(sig, recid).unwrap())
+        };
+
+        let tx = TxEip1559 { chain_id: 1, to: sender.into(), ..Default::default() };
+        assert!(sign(tx.clone()).is_self_transfer().unwrap());
+
+        let other = TxEip1559 { to: Address::ZERO.into(), ..tx.clone() };
+        assert!(!sign(other).is_self_transfer().unwrap());
+
+        let create = TxEip1559 { to: TxKind::Create, ..tx };
+        assert!(!sign(create).is_self_transfer().unwrap());
+    }
 }
@@ -1,6 +1,6 @@
-use crate::{SignableTransaction, Signed, Transaction, TxType};
+use crate::{AccessListUnsupported, SignableTransaction, Signed, Transaction, TxType};
 use alloy_eips::eip2930::AccessList;
-use alloy_primitives::{keccak256, Bytes, ChainId, Signature, TxKind, U256};
+use alloy_primitives::{keccak256, Bytes, ChainId, Signature, TxKind, B256, U256};
 use alloy_rlp::{length_of_length, BufMut, Decodable, Encodable, Header};
 use core::mem;
 
@@ -72,6 +72,32 @@ impl TxEip2930 {
         self.input.len() // input
     }
 
+    /// Returns the transaction with its gas limit increased by `percent`, e.g. to add a safety
+    /// margin on top of a gas estimate.
+    ///
+    /// The result is rounded down and saturates at `u128::MAX` instead of overflowing.
+    pub const fn with_gas_margin(mut self, percent: u16) -> Self {
+        self.gas_limit = super::gas_limit_with_margin(self.gas_limit, percent);
+        self
+    }
+
+    /// Returns the transaction with its gas limit lowered to `max` if it exceeds it, e.g. to cap
+    /// it at the block gas limit.
+    pub const fn clamp_gas_limit(mut self, max: u64) -> Self {
+        if self.gas_limit > max as u128 {
+            self.gas_limit = max as u128;
+        }
+        self
+    }
+
+    /// Increases the fees of the transaction by at least `percent`, e.g. to replace a pending
+    /// transaction with the same nonce. Most nodes require a bump of at least 10%.
+    ///
+    /// The fees are rounded up and saturate at `u128::MAX` instead of overflowing.
+    pub fn bump_fees(&mut self, percent: u16) {
+        self.gas_price = super::fee_with_bump(self.gas_price, percent);
+    }
+
     /// Decodes the inner [TxEip2930] fields from RLP bytes.
     ///
     /// NOTE: This assumes a RLP header has already been decoded, and _just_ decodes the following
@@ -125,6 +151,13 @@ impl TxEip2930 {
         self.access_list.encode(out);
     }
 
+    /// Returns the RLP encoding of the transaction's fields, without a RLP header.
+    pub fn encode_fields_to_bytes(&self) -> Bytes {
+        let mut out = Vec::with_capacity(self.fields_len());
+        self.encode_fields(&mut out);
+        out.into()
+    }
+
     /// Returns what the encoded length should be, if the transaction were RLP encoded with the
     /// given signature, depending on the value of `with_header`.
     ///
@@ -224,6 +257,28 @@ impl TxEip2930 {
     }
 }
 
+impl Signed<TxEip2930> {
+    /// Returns the [EIP-2718] encoding of the signed transaction: `tx_type || rlp(payload)`.
+    ///
+    /// [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+    pub fn encoded_2718(&self) -> Bytes {
+        let mut out = Vec::new();
+        self.tx().encode_with_signature(self.signature(), &mut out, false);
+        out.into()
+    }
+
+    /// Returns the network encoding of the signed transaction, as used by the p2p protocol.
+    ///
+    /// Unlike [`encoded_2718`](Self::encoded_2718), this wraps the encoding in an RLP string
+    /// header: `rlp(tx_type || rlp(payload))`. This is the form in which typed transactions
+    /// appear in RLP lists, e.g. block bodies.
+    pub fn network_encoded(&self) -> Bytes {
+        let mut out = Vec::new();
+        self.tx().encode_with_signature(self.signature(), &mut out, true);
+        out.into()
+    }
+}
+
 impl Transaction for TxEip2930 {
     fn chain_id(&self) -> Option<ChainId> {
         Some(self.chain_id)
@@ -252,6 +307,30 @@ impl Transaction for TxEip2930 {
     fn input(&self) -> &[u8] {
         &self.input
     }
+
+    fn max_fee_per_gas(&self) -> u128 {
+        self.gas_price
+    }
+
+    fn max_priority_fee_per_gas(&self) -> Option<u128> {
+        None
+    }
+
+    fn max_fee_per_blob_gas(&self) -> Option<u128> {
+        None
+    }
+
+    fn ty(&self) -> u8 {
+        TxType::Eip2930 as u8
+    }
+
+    fn access_list(&self) -> Option<&AccessList> {
+        Some(&self.access_list)
+    }
+
+    fn blob_versioned_hashes(&self) -> Option<&[B256]> {
+        None
+    }
 }
 
 impl SignableTransaction<Signature> for TxEip2930 {
@@ -259,6 +338,18 @@ impl SignableTransaction<Signature> for TxEip2930 {
         self.chain_id = chain_id;
     }
 
+    fn with_access_list(
+        mut self,
+        access_list: AccessList,
+    ) -> Result<Self, AccessListUnsupported<Self>> {
+        self.access_list = access_list;
+        Ok(self)
+    }
+
+    fn bump_fees(&mut self, percent: u16) {
+        self.bump_fees(percent);
+    }
+
     fn encode_for_signing(&self, out: &mut dyn BufMut) {
         out.put_u8(self.tx_type() as u8);
         Header { list: true, payload_length: self.fields_len() }.encode(out);
@@ -271,6 +362,10 @@ impl SignableTransaction<Signature> for TxEip2930 {
         1 + Header { list: true, payload_length }.length() + payload_length
     }
 
+    fn unsigned_fingerprint(&self) -> B256 {
+        keccak256(self.encode_fields_to_bytes())
+    }
+
     fn into_signed(self, signature: Signature) -> Signed<Self> {
         let mut buf = Vec::with_capacity(self.encoded_len_with_signature(&signature, false));
         self.encode_with_signature(&signature, &mut buf, false);
@@ -1,4 +1,6 @@
-use crate::{SignableTransaction, Signed, Transaction, TxType};
+use crate::{
+    AccessListUnsupported, FeeMarketUnsupported, SignableTransaction, Signed, Transaction, TxType,
+};
 
 use alloy_eips::{eip2930::AccessList, eip4844::DATA_GAS_PER_BLOB};
 use alloy_primitives::{keccak256, Address, Bytes, ChainId, Signature, TxKind, B256, U256};
@@ -96,6 +98,16 @@ impl TxEip4844Variant {
         TxType::Eip4844
     }
 
+    /// Increases the fees of the transaction by at least `percent`.
+    ///
+    /// See [`TxEip4844::bump_fees`].
+    pub fn bump_fees(&mut self, percent: u16) {
+        match self {
+            Self::TxEip4844(tx) => tx.bump_fees(percent),
+            Self::TxEip4844WithSidecar(tx) => tx.tx.bump_fees(percent),
+        }
+    }
+
     /// Get access to the inner tx [TxEip4844].
     #[doc(alias = "transaction")]
     pub const fn tx(&self) -> &TxEip4844 {
@@ -191,6 +203,45 @@ impl TxEip4844Variant {
     }
 }
 
+impl Signed<TxEip4844Variant> {
+    /// Computes the transaction hash over the consensus encoding of the transaction, explicitly
+    /// excluding any blob sidecar.
+    ///
+    /// The [EIP-4844] transaction hash commits only to the transaction body and signature, so
+    /// this is always equal to [`Signed::hash`] for correctly constructed transactions, regardless
+    /// of whether a sidecar is attached.
+    ///
+    /// [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
+    pub fn tx_hash_without_sidecar(&self) -> B256 {
+        let tx = self.tx().tx();
+        let mut buf = Vec::with_capacity(tx.encoded_len_with_signature(self.signature(), false));
+        tx.encode_with_signature(self.signature(), &mut buf, false);
+        keccak256(&buf)
+    }
+
+    /// Returns the [EIP-2718] encoding of the signed transaction: `tx_type || rlp(payload)`.
+    ///
+    /// If a sidecar is attached, the payload includes the blobs, commitments and proofs.
+    ///
+    /// [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+    pub fn encoded_2718(&self) -> Bytes {
+        let mut out = Vec::new();
+        self.tx().encode_with_signature(self.signature(), &mut out, false);
+        out.into()
+    }
+
+    /// Returns the network encoding of the signed transaction, as used by the p2p protocol.
+    ///
+    /// Unlike [`encoded_2718`](Self::encoded_2718), this wraps the encoding in an RLP string
+    /// header: `rlp(tx_type || rlp(payload))`. This is the form in which typed transactions
+    /// appear in RLP lists, e.g. block bodies.
+    pub fn network_encoded(&self) -> Bytes {
+        let mut out = Vec::new();
+        self.tx().encode_with_signature(self.signature(), &mut out, true);
+        out.into()
+    }
+}
+
 impl Transaction for TxEip4844Variant {
     fn chain_id(&self) -> Option<ChainId> {
         match self {
@@ -238,6 +289,30 @@ impl Transaction for TxEip4844Variant {
             Self::TxEip4844WithSidecar(tx) => tx.tx().input.as_ref(),
         }
     }
+
+    fn max_fee_per_gas(&self) -> u128 {
+        self.tx().max_fee_per_gas()
+    }
+
+    fn max_priority_fee_per_gas(&self) -> Option<u128> {
+        self.tx().max_priority_fee_per_gas()
+    }
+
+    fn max_fee_per_blob_gas(&self) -> Option<u128> {
+        self.tx().max_fee_per_blob_gas()
+    }
+
+    fn ty(&self) -> u8 {
+        self.tx().ty()
+    }
+
+    fn access_list(&self) -> Option<&AccessList> {
+        self.tx().access_list()
+    }
+
+    fn blob_versioned_hashes(&self) -> Option<&[B256]> {
+        self.tx().blob_versioned_hashes()
+    }
 }
 
 impl SignableTransaction<Signature> for TxEip4844Variant {
@@ -252,6 +327,35 @@ impl SignableTransaction<Signature> for TxEip4844Variant {
         }
     }
 
+    fn with_access_list(
+        mut self,
+        access_list: AccessList,
+    ) -> Result<Self, AccessListUnsupported<Self>> {
+        match &mut self {
+            Self::TxEip4844(inner) => inner.access_list = access_list,
+            Self::TxEip4844WithSidecar(inner) => inner.tx.access_list = access_list,
+        }
+        Ok(self)
+    }
+
+    fn apply_fee_suggestion(
+        mut self,
+        max_fee_per_gas: u128,
+        max_priority_fee_per_gas: u128,
+    ) -> Result<Self, FeeMarketUnsupported<Self>> {
+        let tx = match &mut self {
+            Self::TxEip4844(inner) => inner,
+            Self::TxEip4844WithSidecar(inner) => &mut inner.tx,
+        };
+        tx.max_fee_per_gas = max_fee_per_gas;
+        tx.max_priority_fee_per_gas = max_priority_fee_per_gas;
+        Ok(self)
+    }
+
+    fn bump_fees(&mut self, percent: u16) {
+        self.bump_fees(percent);
+    }
+
     fn encode_for_signing(&self, out: &mut dyn alloy_rlp::BufMut) {
         // A signature for a [TxEip4844WithSidecar] is a signature over the [TxEip4844Variant]
         // EIP-2718 payload fields:
@@ -267,6 +371,10 @@ impl SignableTransaction<Signature> for TxEip4844Variant {
         1 + length_of_length(payload_length) + payload_length
     }
 
+    fn unsigned_fingerprint(&self) -> B256 {
+        self.tx().unsigned_fingerprint()
+    }
+
     fn into_signed(self, signature: Signature) -> Signed<Self> {
         let payload_length = 1 + self.fields_len() + signature.rlp_vrs_len();
         let mut buf = Vec::with_capacity(payload_length);
@@ -468,6 +576,13 @@ impl TxEip4844 {
         self.blob_versioned_hashes.encode(out);
     }
 
+    /// Returns the RLP encoding of the transaction's fields, without a RLP header.
+    pub fn encode_fields_to_bytes(&self) -> Bytes {
+        let mut out = Vec::with_capacity(self.fields_len());
+        self.encode_fields(&mut out);
+        out.into()
+    }
+
     /// Calculates a heuristic for the in-memory size of the [TxEip4844Variant] transaction.
     #[inline]
     pub fn size(&self) -> usize {
@@ -484,6 +599,38 @@ impl TxEip4844 {
         mem::size_of::<u128>() // max_fee_per_data_gas
     }
 
+    /// Returns the transaction with its gas limit increased by `percent`, e.g. to add a safety
+    /// margin on top of a gas estimate.
+    ///
+    /// The result is rounded down and saturates at `u128::MAX` instead of overflowing.
+    pub const fn with_gas_margin(mut self, percent: u16) -> Self {
+        self.gas_limit = super::gas_limit_with_margin(self.gas_limit, percent);
+        self
+    }
+
+    /// Returns the transaction with its gas limit lowered to `max` if it exceeds it, e.g. to cap
+    /// it at the block gas limit.
+    pub const fn clamp_gas_limit(mut self, max: u64) -> Self {
+        if self.gas_limit > max as u128 {
+            self.gas_limit = max as u128;
+        }
+        self
+    }
+
+    /// Increases the fees of the transaction by at least `percent`, e.g. to replace a pending
+    /// transaction with the same nonce. Most nodes require a bump of at least 10%.
+    ///
+    /// The fees are rounded up and saturate at `u128::MAX` instead of overflowing.
+    ///
+    /// The max fee per blob gas is bumped by the same percentage. Note that some nodes require a
+    /// larger bump for the blob fee of replacement blob transactions.
+    pub fn bump_fees(&mut self, percent: u16) {
+        self.max_fee_per_gas = super::fee_with_bump(self.max_fee_per_gas, percent);
+        self.max_priority_fee_per_gas =
+            super::fee_with_bump(self.max_priority_fee_per_gas, percent);
+        self.max_fee_per_blob_gas = super::fee_with_bump(self.max_fee_per_blob_gas, percent);
+    }
+
     /// Returns what the encoded length should be, if the transaction were RLP encoded with the
     /// given signature, depending on the value of `with_header`.
     ///
@@ -608,6 +755,28 @@ impl SignableTransaction<Signature> for TxEip4844 {
         self.chain_id = chain_id;
     }
 
+    fn with_access_list(
+        mut self,
+        access_list: AccessList,
+    ) -> Result<Self, AccessListUnsupported<Self>> {
+        self.access_list = access_list;
+        Ok(self)
+    }
+
+    fn apply_fee_suggestion(
+        mut self,
+        max_fee_per_gas: u128,
+        max_priority_fee_per_gas: u128,
+    ) -> Result<Self, FeeMarketUnsupported<Self>> {
+        self.max_fee_per_gas = max_fee_per_gas;
+        self.max_priority_fee_per_gas = max_priority_fee_per_gas;
+        Ok(self)
+    }
+
+    fn bump_fees(&mut self, percent: u16) {
+        self.bump_fees(percent);
+    }
+
     fn encode_for_signing(&self, out: &mut dyn alloy_rlp::BufMut) {
         self.encode_for_signing(out);
     }
@@ -616,6 +785,10 @@ impl SignableTransaction<Signature> for TxEip4844 {
         self.payload_len_for_signature()
     }
 
+    fn unsigned_fingerprint(&self) -> B256 {
+        keccak256(self.encode_fields_to_bytes())
+    }
+
     fn into_signed(self, signature: Signature) -> Signed<Self> {
         let mut buf = Vec::with_capacity(self.encoded_len_with_signature(&signature, false));
         self.encode_with_signature(&signature, &mut buf, false);
@@ -656,6 +829,30 @@ impl Transaction for TxEip4844 {
     fn input(&self) -> &[u8] {
         &self.input
     }
+
+    fn max_fee_per_gas(&self) -> u128 {
+        self.max_fee_per_gas
+    }
+
+    fn max_priority_fee_per_gas(&self) -> Option<u128> {
+        Some(self.max_priority_fee_per_gas)
+    }
+
+    fn max_fee_per_blob_gas(&self) -> Option<u128> {
+        Some(self.max_fee_per_blob_gas)
+    }
+
+    fn ty(&self) -> u8 {
+        TxType::Eip4844 as u8
+    }
+
+    fn access_list(&self) -> Option<&AccessList> {
+        Some(&self.access_list)
+    }
+
+    fn blob_versioned_hashes(&self) -> Option<&[B256]> {
+        Some(&self.blob_versioned_hashes)
+    }
 }
 
 impl Encodable for TxEip4844 {
@@ -831,6 +1028,28 @@ impl SignableTransaction<Signature> for TxEip4844WithSidecar {
         self.tx.chain_id = chain_id;
     }
 
+    fn with_access_list(
+        mut self,
+        access_list: AccessList,
+    ) -> Result<Self, AccessListUnsupported<Self>> {
+        self.tx.access_list = access_list;
+        Ok(self)
+    }
+
+    fn apply_fee_suggestion(
+        mut self,
+        max_fee_per_gas: u128,
+        max_priority_fee_per_gas: u128,
+    ) -> Result<Self, FeeMarketUnsupported<Self>> {
+        self.tx.max_fee_per_gas = max_fee_per_gas;
+        self.tx.max_priority_fee_per_gas = max_priority_fee_per_gas;
+        Ok(self)
+    }
+
+    fn bump_fees(&mut self, percent: u16) {
+        self.tx.bump_fees(percent);
+    }
+
     fn encode_for_signing(&self, out: &mut dyn alloy_rlp::BufMut) {
         // A signature for a [TxEip4844WithSidecar] is a signature over the [TxEip4844] EIP-2718
         // payload fields:
@@ -846,6 +1065,10 @@ impl SignableTransaction<Signature> for TxEip4844WithSidecar {
         self.tx.payload_len_for_signature()
     }
 
+    fn unsigned_fingerprint(&self) -> B256 {
+        self.tx.unsigned_fingerprint()
+    }
+
     fn into_signed(self, signature: Signature) -> Signed<Self, Signature> {
         let mut buf = Vec::with_capacity(self.tx.encoded_len_with_signature(&signature, false));
         // The sidecar is NOT included in the signed payload, only the transaction fields and the
@@ -891,12 +1114,36 @@ impl Transaction for TxEip4844WithSidecar {
     fn input(&self) -> &[u8] {
         self.tx.input()
     }
+
+    fn max_fee_per_gas(&self) -> u128 {
+        self.tx.max_fee_per_gas()
+    }
+
+    fn max_priority_fee_per_gas(&self) -> Option<u128> {
+        self.tx.max_priority_fee_per_gas()
+    }
+
+    fn max_fee_per_blob_gas(&self) -> Option<u128> {
+        self.tx.max_fee_per_blob_gas()
+    }
+
+    fn ty(&self) -> u8 {
+        self.tx.ty()
+    }
+
+    fn access_list(&self) -> Option<&AccessList> {
+        self.tx.access_list()
+    }
+
+    fn blob_versioned_hashes(&self) -> Option<&[B256]> {
+        self.tx.blob_versioned_hashes()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{BlobTransactionSidecar, TxEip4844, TxEip4844WithSidecar};
-    use crate::{transaction::eip4844::TxEip4844Variant, SignableTransaction, TxEnvelope};
+    use crate::{transaction::eip4844::TxEip4844Variant, SignableTransaction, Signed, TxEnvelope};
     use alloy_eips::eip2930::AccessList;
     use alloy_primitives::{address, b256, bytes, Signature, U256};
     use alloy_rlp::{Decodable, Encodable};
@@ -942,6 +1189,18 @@ mod tests {
         // the hashes should be the same
         assert_eq!(expected_signed.hash(), actual_signed.hash());
 
+        // and should match the hash computed without the sidecar
+        let expected_variant: Signed<TxEip4844Variant> =
+            TxEnvelope::from(expected_signed.clone()).as_eip4844().cloned().unwrap();
+        let actual_variant: Signed<TxEip4844Variant> =
+            TxEnvelope::from(actual_signed.clone()).as_eip4844().cloned().unwrap();
+        assert_eq!(expected_variant.tx_hash_without_sidecar(), *expected_variant.hash());
+        assert_eq!(actual_variant.tx_hash_without_sidecar(), *actual_variant.hash());
+        assert_eq!(
+            expected_variant.tx_hash_without_sidecar(),
+            actual_variant.tx_hash_without_sidecar()
+        );
+
         // convert to envelopes
         let expected_envelope: TxEnvelope = expected_signed.into();
         let actual_envelope: TxEnvelope = actual_signed.into();
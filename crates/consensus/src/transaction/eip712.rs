@@ -0,0 +1,102 @@
+//! The [EIP-712] typed data representation of a transaction.
+//!
+//! [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+
+alloy_sol_types::sol! {
+    /// A transaction as an [EIP-712] typed data struct, used by smart-contract wallets that sign
+    /// transactions as typed data rather than raw RLP.
+    ///
+    /// The struct type is:
+    ///
+    /// ```text
+    /// Transaction(uint256 nonce,address to,uint256 value,bytes data,uint256 gasLimit,uint256 maxFeePerGas,uint256 maxPriorityFeePerGas)
+    /// ```
+    ///
+    /// The chain ID is not part of the struct, as it is committed to by the [`Eip712Domain`].
+    ///
+    /// See [`Transaction::to_eip712_struct`](crate::Transaction::to_eip712_struct).
+    ///
+    /// [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+    /// [`Eip712Domain`]: alloy_sol_types::Eip712Domain
+    #[derive(Debug, Default, PartialEq, Eq, Hash)]
+    struct Transaction {
+        /// The nonce of the transaction.
+        uint256 nonce;
+        /// The recipient of the transaction, or the zero address for a contract creation.
+        address to;
+        /// The value transferred by the transaction.
+        uint256 value;
+        /// The calldata, or init code for a contract creation.
+        bytes data;
+        /// The gas limit of the transaction.
+        uint256 gasLimit;
+        /// The max fee per gas, or the gas price of a legacy or EIP-2930 transaction.
+        uint256 maxFeePerGas;
+        /// The max priority fee per gas, or the gas price of a legacy or EIP-2930 transaction.
+        uint256 maxPriorityFeePerGas;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Transaction, TxEip1559, TxLegacy};
+    use alloy_primitives::{address, bytes, keccak256, Address, B256, U256};
+    use alloy_sol_types::{eip712_domain, SolStruct};
+
+    const TYPE_STRING: &str = "Transaction(uint256 nonce,address to,uint256 value,bytes data,uint256 gasLimit,uint256 maxFeePerGas,uint256 maxPriorityFeePerGas)";
+
+    fn word(value: u128) -> B256 {
+        U256::from(value).into()
+    }
+
+    #[test]
+    fn eip712_struct_hash() {
+        let domain = eip712_domain! {
+            name: "Wallet",
+            version: "1",
+            chain_id: 1,
+            verifying_contract: address!("6069a6c32cf691f5982febae4faf8a6f3ab2f0f6"),
+        };
+        let to = address!("dd6b8b3dc6b7ad97db52f08a275ff4483e024cea");
+        let tx = TxEip1559 {
+            chain_id: 1,
+            nonce: 7,
+            gas_limit: 44386,
+            to: to.into(),
+            value: U256::from(3),
+            input: bytes!("a22cb465"),
+            max_fee_per_gas: 0x4a817c800,
+            max_priority_fee_per_gas: 0x3b9aca00,
+            ..Default::default()
+        };
+
+        let encoded = [
+            keccak256(TYPE_STRING),
+            word(7),
+            to.into_word(),
+            word(3),
+            keccak256([0xa2, 0x2c, 0xb4, 0x65]),
+            word(44386),
+            word(0x4a817c800),
+            word(0x3b9aca00),
+        ]
+        .concat();
+        let struct_hash = keccak256(encoded);
+        let digest = keccak256(
+            [&[0x19, 0x01][..], domain.separator().as_slice(), struct_hash.as_slice()].concat(),
+        );
+
+        assert_eq!(super::Transaction::eip712_encode_type(), TYPE_STRING);
+        assert_eq!(tx.to_eip712_struct().eip712_hash_struct(), struct_hash);
+        assert_eq!(tx.to_eip712_struct_hash(&domain), digest);
+    }
+
+    #[test]
+    fn eip712_struct_legacy_create() {
+        let tx = TxLegacy { nonce: 1, gas_price: 9, gas_limit: 53000, ..Default::default() };
+        let eip712 = tx.to_eip712_struct();
+        assert_eq!(eip712.to, Address::ZERO);
+        assert_eq!(eip712.maxFeePerGas, U256::from(9));
+        assert_eq!(eip712.maxPriorityFeePerGas, U256::from(9));
+    }
+}
@@ -66,6 +66,47 @@ impl TryFrom<u8> for TxType {
     }
 }
 
+/// Counts the transactions of each [`TxType`] in an RLP list of network encoded transactions,
+/// such as the transactions of a block body, advancing the buffer past the list.
+///
+/// The returned counts are indexed by `TxType as usize`. Only the RLP headers and [EIP-2718]
+/// type bytes are read: the transactions are not decoded, so their fields are not validated.
+///
+/// [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+pub fn classify_transactions(buf: &mut &[u8]) -> Eip2718Result<[usize; 4]> {
+    let header = Header::decode(buf)?;
+    if !header.list {
+        return Err(alloy_rlp::Error::UnexpectedString.into());
+    }
+    if buf.len() < header.payload_length {
+        return Err(alloy_rlp::Error::InputTooShort.into());
+    }
+    let (mut payload, rest) = buf.split_at(header.payload_length);
+
+    let mut counts = [0; 4];
+    while !payload.is_empty() {
+        let item = Header::decode(&mut payload)?;
+        if payload.len() < item.payload_length {
+            return Err(alloy_rlp::Error::InputTooShort.into());
+        }
+        let ty = if item.list {
+            TxType::Legacy
+        } else {
+            // an empty string item has no type byte
+            match payload[..item.payload_length].first().copied().map(TxType::try_from) {
+                Some(Ok(TxType::Legacy)) => return Err(Eip2718Error::UnexpectedType(0)),
+                Some(ty) => ty?,
+                None => return Err(alloy_rlp::Error::InputTooShort.into()),
+            }
+        };
+        counts[ty as usize] += 1;
+        payload = &payload[item.payload_length..];
+    }
+
+    *buf = rest;
+    Ok(counts)
+}
+
 /// The Ethereum [EIP-2718] Transaction Envelope.
 ///
 /// # Note:
@@ -530,6 +571,57 @@ mod tests {
         assert_eq!(transactions, decoded);
     }
 
+    #[test]
+    fn classify_transactions() {
+        let signature = Signature::test_signature();
+        let legacy: TxEnvelope = TxLegacy::default().into_signed(signature).into();
+        let eip2930: TxEnvelope = TxEip2930::default().into_signed(signature).into();
+        let eip1559: TxEnvelope = TxEip1559::default().into_signed(signature).into();
+        let eip4844: TxEnvelope =
+            TxEip4844Variant::from(TxEip4844::default()).into_signed(signature).into();
+        let transactions = vec![
+            eip1559.clone(),
+            legacy.clone(),
+            eip4844,
+            eip1559.clone(),
+            eip2930,
+            legacy,
+            eip1559,
+        ];
+
+        let mut encoded = alloy_rlp::encode(&transactions);
+        encoded.push(0xff);
+        let mut buf = encoded.as_slice();
+        assert_eq!(super::classify_transactions(&mut buf).unwrap(), [2, 1, 3, 1]);
+        assert_eq!(buf, [0xff]);
+
+        let empty = alloy_rlp::encode(Vec::<TxEnvelope>::new());
+        assert_eq!(super::classify_transactions(&mut empty.as_slice()).unwrap(), [0; 4]);
+
+        // a single typed transaction of unknown type 0x05
+        assert!(matches!(
+            super::classify_transactions(&mut &hex!("c3 82 05c0")[..]),
+            Err(Eip2718Error::UnexpectedType(5))
+        ));
+        assert!(matches!(
+            super::classify_transactions(&mut &hex!("c3 82 00c0")[..]),
+            Err(Eip2718Error::UnexpectedType(0))
+        ));
+        assert!(matches!(
+            super::classify_transactions(&mut &hex!("c3 8302")[..]),
+            Err(Eip2718Error::RlpError(alloy_rlp::Error::InputTooShort))
+        ));
+        // an empty string item must not read the type byte of the next item
+        assert!(matches!(
+            super::classify_transactions(&mut &hex!("c2 80 02")[..]),
+            Err(Eip2718Error::RlpError(alloy_rlp::Error::InputTooShort))
+        ));
+        assert!(matches!(
+            super::classify_transactions(&mut &hex!("820000")[..]),
+            Err(Eip2718Error::RlpError(alloy_rlp::Error::UnexpectedString))
+        ));
+    }
+
     #[test]
     fn decode_encode_known_rpc_transaction() {
         // test data pulled from hive test that sends blob transactions
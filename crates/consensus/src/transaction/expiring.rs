@@ -0,0 +1,137 @@
+use crate::Transaction;
+use alloy_eips::eip2930::AccessList;
+use alloy_primitives::{ChainId, TxKind, B256, U256};
+
+/// A transaction that is only valid up to a deadline.
+///
+/// Base Ethereum transactions have no expiry, but some networks reject transactions past a
+/// deadline. This wrapper attaches such a deadline to any transaction without changing its
+/// encoding, and implements [`Transaction`] by delegating to the inner transaction.
+///
+/// The unit of [`valid_until`](Self::valid_until) is up to the caller, e.g. a block number or a
+/// UNIX timestamp, as long as it matches the `now` passed to [`is_expired`](Self::is_expired).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ExpiringTx<T> {
+    /// The inner transaction.
+    pub tx: T,
+    /// The last moment at which the transaction is still valid.
+    pub valid_until: u64,
+}
+
+impl<T> ExpiringTx<T> {
+    /// Create a new [ExpiringTx] valid up to and including `valid_until`.
+    pub const fn new(tx: T, valid_until: u64) -> Self {
+        Self { tx, valid_until }
+    }
+
+    /// Returns `true` if the transaction is no longer valid at `now`, i.e. if `now` is past
+    /// [`valid_until`](Self::valid_until).
+    pub const fn is_expired(&self, now: u64) -> bool {
+        now > self.valid_until
+    }
+
+    /// Consume the wrapper, returning the inner transaction.
+    pub fn into_inner(self) -> T {
+        self.tx
+    }
+}
+
+impl<T: Transaction> Transaction for ExpiringTx<T> {
+    fn chain_id(&self) -> Option<ChainId> {
+        self.tx.chain_id()
+    }
+
+    fn nonce(&self) -> u64 {
+        self.tx.nonce()
+    }
+
+    fn gas_limit(&self) -> u128 {
+        self.tx.gas_limit()
+    }
+
+    fn gas_price(&self) -> Option<u128> {
+        self.tx.gas_price()
+    }
+
+    fn max_fee_per_gas(&self) -> u128 {
+        self.tx.max_fee_per_gas()
+    }
+
+    fn max_priority_fee_per_gas(&self) -> Option<u128> {
+        self.tx.max_priority_fee_per_gas()
+    }
+
+    fn max_fee_per_blob_gas(&self) -> Option<u128> {
+        self.tx.max_fee_per_blob_gas()
+    }
+
+    fn to(&self) -> TxKind {
+        self.tx.to()
+    }
+
+    fn value(&self) -> U256 {
+        self.tx.value()
+    }
+
+    fn input(&self) -> &[u8] {
+        self.tx.input()
+    }
+
+    fn ty(&self) -> u8 {
+        self.tx.ty()
+    }
+
+    fn access_list(&self) -> Option<&AccessList> {
+        self.tx.access_list()
+    }
+
+    fn blob_versioned_hashes(&self) -> Option<&[B256]> {
+        self.tx.blob_versioned_hashes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TxEip1559, TxType};
+    use alloy_primitives::{address, bytes};
+
+    #[test]
+    fn is_expired() {
+        let tx = ExpiringTx::new(TxEip1559::default(), 100);
+        assert!(!tx.is_expired(99));
+        assert!(!tx.is_expired(100));
+        assert!(tx.is_expired(101));
+    }
+
+    #[test]
+    fn delegates_to_inner() {
+        let inner = TxEip1559 {
+            chain_id: 1,
+            nonce: 7,
+            gas_limit: 21_000,
+            max_fee_per_gas: 100,
+            max_priority_fee_per_gas: 2,
+            to: address!("6069a6c32cf691f5982febae4faf8a6f3ab2f0f6").into(),
+            value: U256::from(3),
+            input: bytes!("a9059cbb"),
+            ..Default::default()
+        };
+        let tx = ExpiringTx::new(inner.clone(), 100);
+
+        assert_eq!(tx.chain_id(), Some(1));
+        assert_eq!(tx.nonce(), 7);
+        assert_eq!(tx.gas_limit(), 21_000);
+        assert_eq!(tx.gas_price(), None);
+        assert_eq!(tx.max_fee_per_gas(), 100);
+        assert_eq!(tx.max_priority_fee_per_gas(), Some(2));
+        assert_eq!(tx.to(), inner.to);
+        assert_eq!(tx.value(), U256::from(3));
+        assert_eq!(tx.input(), inner.input.as_ref());
+        assert_eq!(tx.ty(), TxType::Eip1559 as u8);
+        assert_eq!(tx.access_list(), Some(&inner.access_list));
+        assert_eq!(tx.selector(), inner.selector());
+        assert_eq!(tx.intrinsic_gas(), inner.intrinsic_gas());
+        assert_eq!(tx.into_inner(), inner);
+    }
+}
@@ -1,5 +1,6 @@
-use crate::{SignableTransaction, Signed, Transaction};
-use alloy_primitives::{keccak256, Bytes, ChainId, Signature, TxKind, U256};
+use crate::{SignableTransaction, Signed, Transaction, TxType};
+use alloy_eips::eip2930::AccessList;
+use alloy_primitives::{keccak256, Bytes, ChainId, Signature, TxKind, B256, U256};
 use alloy_rlp::{length_of_length, BufMut, Decodable, Encodable, Header, Result};
 use core::mem;
 
@@ -74,6 +75,32 @@ impl TxLegacy {
         self.input.len() // input
     }
 
+    /// Returns the transaction with its gas limit increased by `percent`, e.g. to add a safety
+    /// margin on top of a gas estimate.
+    ///
+    /// The result is rounded down and saturates at `u128::MAX` instead of overflowing.
+    pub const fn with_gas_margin(mut self, percent: u16) -> Self {
+        self.gas_limit = super::gas_limit_with_margin(self.gas_limit, percent);
+        self
+    }
+
+    /// Returns the transaction with its gas limit lowered to `max` if it exceeds it, e.g. to cap
+    /// it at the block gas limit.
+    pub const fn clamp_gas_limit(mut self, max: u64) -> Self {
+        if self.gas_limit > max as u128 {
+            self.gas_limit = max as u128;
+        }
+        self
+    }
+
+    /// Increases the fees of the transaction by at least `percent`, e.g. to replace a pending
+    /// transaction with the same nonce. Most nodes require a bump of at least 10%.
+    ///
+    /// The fees are rounded up and saturate at `u128::MAX` instead of overflowing.
+    pub fn bump_fees(&mut self, percent: u16) {
+        self.gas_price = super::fee_with_bump(self.gas_price, percent);
+    }
+
     /// Outputs the length of the transaction's fields, without a RLP header or length of the
     /// eip155 fields.
     #[doc(hidden)]
@@ -99,6 +126,13 @@ impl TxLegacy {
         self.input.0.encode(out);
     }
 
+    /// Returns the RLP encoding of the transaction's fields, without a RLP header or eip155 fields.
+    pub fn encode_fields_to_bytes(&self) -> Bytes {
+        let mut out = Vec::with_capacity(self.fields_len());
+        self.encode_fields(&mut out);
+        out.into()
+    }
+
     /// Encodes the transaction from RLP bytes, including the signature. This __does not__ encode a
     /// tx type byte or string header.
     ///
@@ -197,6 +231,57 @@ impl TxLegacy {
     }
 }
 
+/// Computes the [EIP-155] `v` value of a signature: `chain_id * 2 + 35 + y_parity`.
+///
+/// Returns `None` if the value overflows a `u64`, which can only happen for chain IDs above
+/// `(u64::MAX - 36) / 2`. See [`recover_chain_id`] for the inverse.
+///
+/// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+#[inline]
+pub const fn eip155_v(chain_id: ChainId, y_parity: bool) -> Option<u64> {
+    match chain_id.checked_mul(2) {
+        Some(v) => v.checked_add(35 + y_parity as u64),
+        None => None,
+    }
+}
+
+/// Recovers the chain ID from an [EIP-155] `v` value.
+///
+/// Returns `None` if `v` is not an EIP-155 value, i.e. is lower than `35`, as is the case for
+/// pre-EIP-155 legacy signatures (`27` or `28`) and typed transactions (`0` or `1`).
+///
+/// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+#[inline]
+pub const fn recover_chain_id(v: u64) -> Option<ChainId> {
+    if v >= 35 {
+        Some((v - 35) / 2)
+    } else {
+        None
+    }
+}
+
+impl Signed<TxLegacy> {
+    /// Returns the [EIP-2718] encoding of the signed transaction, i.e. the bare RLP list.
+    ///
+    /// Legacy transactions have no type byte, so this is identical to
+    /// [`network_encoded`](Self::network_encoded).
+    ///
+    /// [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+    pub fn encoded_2718(&self) -> Bytes {
+        let mut out = Vec::with_capacity(self.tx().encoded_len_with_signature(self.signature()));
+        self.tx().encode_with_signature_fields(self.signature(), &mut out);
+        out.into()
+    }
+
+    /// Returns the network encoding of the signed transaction, as used by the p2p protocol.
+    ///
+    /// Legacy transactions have no type byte, so this is identical to
+    /// [`encoded_2718`](Self::encoded_2718).
+    pub fn network_encoded(&self) -> Bytes {
+        self.encoded_2718()
+    }
+}
+
 impl Transaction for TxLegacy {
     fn chain_id(&self) -> Option<ChainId> {
         self.chain_id
@@ -225,6 +310,30 @@ impl Transaction for TxLegacy {
     fn input(&self) -> &[u8] {
         &self.input
     }
+
+    fn max_fee_per_gas(&self) -> u128 {
+        self.gas_price
+    }
+
+    fn max_priority_fee_per_gas(&self) -> Option<u128> {
+        None
+    }
+
+    fn max_fee_per_blob_gas(&self) -> Option<u128> {
+        None
+    }
+
+    fn ty(&self) -> u8 {
+        TxType::Legacy as u8
+    }
+
+    fn access_list(&self) -> Option<&AccessList> {
+        None
+    }
+
+    fn blob_versioned_hashes(&self) -> Option<&[B256]> {
+        None
+    }
 }
 
 impl SignableTransaction<Signature> for TxLegacy {
@@ -236,6 +345,10 @@ impl SignableTransaction<Signature> for TxLegacy {
         self.chain_id = Some(chain_id);
     }
 
+    fn bump_fees(&mut self, percent: u16) {
+        self.bump_fees(percent);
+    }
+
     fn encode_for_signing(&self, out: &mut dyn BufMut) {
         Header { list: true, payload_length: self.fields_len() + self.eip155_fields_len() }
             .encode(out);
@@ -249,6 +362,13 @@ impl SignableTransaction<Signature> for TxLegacy {
         Header { list: true, payload_length }.length() + payload_length
     }
 
+    fn unsigned_fingerprint(&self) -> B256 {
+        let mut out = Vec::with_capacity(self.fields_len() + self.eip155_fields_len());
+        self.encode_fields(&mut out);
+        self.encode_eip155_signing_fields(&mut out);
+        keccak256(out)
+    }
+
     fn into_signed(self, signature: Signature) -> Signed<Self> {
         let mut buf = Vec::with_capacity(self.encoded_len_with_signature(&signature));
         self.encode_with_signature_fields(&signature, &mut buf);
@@ -348,4 +468,99 @@ mod tests {
         assert_eq!(tx.tx().chain_id, Some(1), "Expected same chain id");
         assert_eq!(expected, recovered, "Expected same signer");
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn legacy_to_request_json() {
+        use crate::Transaction;
+
+        let tx = TxLegacy {
+            chain_id: Some(1),
+            nonce: 0x18,
+            gas_price: 0xfa56ea00,
+            gas_limit: 0x1e8480,
+            to: address!("7a250d5630b4cf539739df2c5dacb4c659f2488d").into(),
+            value: U256::from(0xde0b6b3a7640000_u64),
+            input: hex!("d0e30db0").into(),
+        };
+        let from = address!("a12e1462d0ced572f396f58b6e2d03894cd7c8a4");
+
+        let expected: serde_json::Value = serde_json::from_str(
+            r#"{
+                "from": "0xa12e1462d0ceD572f396F58B6E2D03894cD7C8a4",
+                "to": "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D",
+                "gas": "0x1e8480",
+                "gasPrice": "0xfa56ea00",
+                "value": "0xde0b6b3a7640000",
+                "input": "0xd0e30db0",
+                "nonce": "0x18",
+                "chainId": "0x1",
+                "type": "0x0"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(tx.to_request_json(Some(from)), expected);
+    }
+
+    #[test]
+    fn encoded_2718_legacy() {
+        use crate::TxEnvelope;
+        use alloy_eips::eip2718::Decodable2718;
+        use alloy_rlp::Decodable;
+
+        let tx = TxLegacy {
+            chain_id: Some(1),
+            nonce: 0x18,
+            gas_price: 0xfa56ea00,
+            gas_limit: 119902,
+            to: TxKind::Call(address!("06012c8cf97bead5deae237070f9587f8e7a266d")),
+            value: U256::from(0x1c6bf526340000u64),
+            input: hex!("f7d8c88300000000000000000000000000000000000000000000000000000000000cee6100000000000000000000000000000000000000000000000000000000000ac3e1").into(),
+        };
+        let signed = tx.into_signed(Signature::test_signature());
+
+        let encoded = signed.encoded_2718();
+        assert_eq!(encoded, signed.network_encoded());
+
+        let decoded = TxEnvelope::decode_2718(&mut encoded.as_ref()).unwrap();
+        assert_eq!(decoded.as_legacy().unwrap().hash(), signed.hash());
+        let decoded = TxEnvelope::decode(&mut encoded.as_ref()).unwrap();
+        assert_eq!(decoded.as_legacy().unwrap().hash(), signed.hash());
+    }
+
+    #[test]
+    fn unsigned_fingerprint_includes_chain_id() {
+        let tx = TxLegacy { chain_id: Some(1), nonce: 1, gas_price: 1, ..Default::default() };
+        let fingerprints = [Some(1), Some(5), None]
+            .map(|chain_id| TxLegacy { chain_id, ..tx.clone() }.unsigned_fingerprint());
+        assert_ne!(fingerprints[0], fingerprints[1]);
+        assert_ne!(fingerprints[0], fingerprints[2]);
+        assert_ne!(fingerprints[1], fingerprints[2]);
+        assert_eq!(tx.unsigned_fingerprint(), fingerprints[0]);
+    }
+
+    #[test]
+    fn eip155_v() {
+        use super::{eip155_v, recover_chain_id};
+
+        assert_eq!(eip155_v(1, false), Some(37));
+        assert_eq!(eip155_v(1, true), Some(38));
+        assert_eq!(recover_chain_id(37), Some(1));
+        assert_eq!(recover_chain_id(38), Some(1));
+
+        for chain_id in [0, 5, 137, 11155111] {
+            for y_parity in [false, true] {
+                assert_eq!(recover_chain_id(eip155_v(chain_id, y_parity).unwrap()), Some(chain_id));
+            }
+        }
+
+        let max = (u64::MAX - 36) / 2;
+        assert_eq!(eip155_v(max, true), Some(u64::MAX - 1));
+        assert_eq!(eip155_v(max + 1, false), Some(u64::MAX));
+        assert_eq!(eip155_v(max + 1, true), None);
+        assert_eq!(eip155_v(u64::MAX, false), None);
+
+        assert_eq!(recover_chain_id(27), None);
+        assert_eq!(recover_chain_id(1), None);
+    }
 }
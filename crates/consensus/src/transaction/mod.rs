@@ -1,18 +1,33 @@
 //! Transaction types.
 
-use crate::Signed;
-use alloy_primitives::{keccak256, ChainId, TxKind, B256, U256};
-use core::any;
+use crate::{constants, Signed};
+use alloy_eips::{
+    eip2930::AccessList,
+    eip4844::{DATA_GAS_PER_BLOB, VERSIONED_HASH_VERSION_KZG},
+};
+use alloy_primitives::{keccak256, Address, Bytes, ChainId, TxKind, B256, U256};
+use core::{any, fmt};
 
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
+mod builder;
+pub use builder::{TxBuildError, TxEip1559Builder, TxEip2930Builder, TxLegacyBuilder};
+
+mod call_bundle;
+pub use call_bundle::CallBundleEntry;
+
 mod eip1559;
 pub use eip1559::TxEip1559;
 
 mod eip2930;
 pub use eip2930::TxEip2930;
 
+#[cfg(feature = "eip712")]
+mod eip712;
+#[cfg(feature = "eip712")]
+pub use eip712::Transaction as Eip712Transaction;
+
 /// [EIP-4844] constants, helpers, and types.
 pub mod eip4844;
 
@@ -25,10 +40,16 @@ pub use eip4844::BlobTransactionValidationError;
 pub use eip4844::{TxEip4844, TxEip4844Variant, TxEip4844WithSidecar};
 
 mod envelope;
-pub use envelope::{TxEnvelope, TxType};
+pub use envelope::{classify_transactions, TxEnvelope, TxType};
+
+mod expiring;
+pub use expiring::ExpiringTx;
 
 mod legacy;
-pub use legacy::TxLegacy;
+pub use legacy::{eip155_v, recover_chain_id, TxLegacy};
+
+mod token;
+pub use token::TokenOp;
 
 mod typed;
 pub use typed::TypedTransaction;
@@ -48,6 +69,34 @@ pub trait Transaction: any::Any + Send + Sync + 'static {
     /// Get `gas_price`.
     fn gas_price(&self) -> Option<u128>;
 
+    /// Returns the maximum fee per gas the caller is willing to pay.
+    ///
+    /// For legacy and [EIP-2930] transactions this is the `gas_price`, which is also what the
+    /// default implementation returns, or `0` if there is none.
+    ///
+    /// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+    fn max_fee_per_gas(&self) -> u128 {
+        self.gas_price().unwrap_or_default()
+    }
+
+    /// Returns the [EIP-1559] priority fee the caller is paying to the block author.
+    ///
+    /// This is `None` for transaction types that predate [EIP-1559], and by default.
+    ///
+    /// [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+    fn max_priority_fee_per_gas(&self) -> Option<u128> {
+        None
+    }
+
+    /// Returns the maximum fee per blob gas for [EIP-4844] transactions.
+    ///
+    /// This is `None` for non-[EIP-4844] transactions, and by default.
+    ///
+    /// [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
+    fn max_fee_per_blob_gas(&self) -> Option<u128> {
+        None
+    }
+
     /// Get `to`.
     fn to(&self) -> TxKind;
 
@@ -56,8 +105,702 @@ pub trait Transaction: any::Any + Send + Sync + 'static {
 
     /// Get `data`.
     fn input(&self) -> &[u8];
+
+    /// Returns the [EIP-2718] transaction type.
+    ///
+    /// The default implementation infers the type from the fields the transaction has: blob
+    /// versioned hashes for [`TxType::Eip4844`], a priority fee for [`TxType::Eip1559`], an
+    /// access list for [`TxType::Eip2930`], and [`TxType::Legacy`] otherwise.
+    ///
+    /// [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+    #[doc(alias = "transaction_type", alias = "tx_type")]
+    fn ty(&self) -> u8 {
+        let ty = if self.blob_versioned_hashes().is_some() {
+            TxType::Eip4844
+        } else if self.max_priority_fee_per_gas().is_some() {
+            TxType::Eip1559
+        } else if self.access_list().is_some() {
+            TxType::Eip2930
+        } else {
+            TxType::Legacy
+        };
+        ty as u8
+    }
+
+    /// Returns the [EIP-2930] `access_list`.
+    ///
+    /// This is `None` for transaction types that predate [EIP-2930], and by default.
+    ///
+    /// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+    fn access_list(&self) -> Option<&AccessList> {
+        None
+    }
+
+    /// Returns the blob versioned hashes for [EIP-4844] transactions.
+    ///
+    /// This is `None` for non-[EIP-4844] transactions, and by default.
+    ///
+    /// [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
+    fn blob_versioned_hashes(&self) -> Option<&[B256]> {
+        None
+    }
+
+    /// Returns `true` if the transaction carries calldata, or init code for a contract creation.
+    ///
+    /// See [`SignableTransaction::normalize_input`] for the canonical form of an empty input.
+    fn has_input(&self) -> bool {
+        !self.input().is_empty()
+    }
+
+    /// Returns the 4-byte function selector of the calldata.
+    ///
+    /// This is `None` for contract creations and for calls with less than 4 bytes of input.
+    fn selector(&self) -> Option<[u8; 4]> {
+        if self.to().is_create() {
+            return None;
+        }
+        self.input().get(..4).map(|selector| selector.try_into().unwrap())
+    }
+
+    /// Returns the calldata following the 4-byte function selector.
+    ///
+    /// This is empty if the transaction has no [`selector`](Self::selector).
+    fn input_args(&self) -> &[u8] {
+        match self.selector() {
+            Some(_) => &self.input()[4..],
+            None => &[],
+        }
+    }
+
+    /// Decodes the calldata as an [ERC-20] `transfer`, `transferFrom` or `approve` call.
+    ///
+    /// This only looks at the [selector](Self::selector) and arguments, so it cannot tell whether
+    /// the recipient is actually a token contract. Returns `None` for other selectors and for
+    /// malformed arguments.
+    ///
+    /// [ERC-20]: https://eips.ethereum.org/EIPS/eip-20
+    fn token_operation(&self) -> Option<TokenOp> {
+        TokenOp::decode(self.selector()?, self.input_args())
+    }
+
+    /// Returns a stable key for ordering transactions, e.g. during block construction.
+    ///
+    /// The key is the tuple `(fee, nonce, to)`, where:
+    /// - `fee` is the [priority fee](Self::max_priority_fee_per_gas) for [EIP-1559] style
+    ///   transactions, or the gas price otherwise;
+    /// - `nonce` is the transaction [nonce](Self::nonce);
+    /// - `to` is the recipient address, or `None` for contract creations. This is used instead of
+    ///   [`TxKind`], which has no total order, and only serves as a deterministic tie-breaker.
+    ///
+    /// Tuples compare lexicographically in ascending order. Block builders usually want the
+    /// highest fee first, in which case the fee should be compared in reverse.
+    ///
+    /// [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+    fn ordering_key(&self) -> (u128, u64, Option<Address>) {
+        let fee = self.max_priority_fee_per_gas().unwrap_or_else(|| self.max_fee_per_gas());
+        (fee, self.nonce(), self.to().to().copied())
+    }
+
+    /// Returns the [access list](Self::access_list) flattened into one `(address, storage key)`
+    /// pair per storage key, in order.
+    ///
+    /// Addresses without storage keys are not included. This is empty if the transaction has no
+    /// access list.
+    fn access_list_pairs(&self) -> Vec<(Address, B256)> {
+        self.access_list()
+            .map(|access_list| {
+                access_list
+                    .iter()
+                    .flat_map(|item| item.storage_keys.iter().map(|key| (item.address, *key)))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the intrinsic gas of the transaction, i.e. the gas charged before any execution.
+    ///
+    /// This accounts for the base transaction cost, the calldata cost ([EIP-2028]), the init code
+    /// cost of contract creations ([EIP-3860]) and the access list cost ([EIP-2930]).
+    ///
+    /// [EIP-2028]: https://eips.ethereum.org/EIPS/eip-2028
+    /// [EIP-3860]: https://eips.ethereum.org/EIPS/eip-3860
+    /// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+    fn intrinsic_gas(&self) -> u64 {
+        let input = self.input();
+        let zero_bytes = input.iter().filter(|byte| **byte == 0).count() as u64;
+        let non_zero_bytes = input.len() as u64 - zero_bytes;
+
+        let mut gas = zero_bytes * constants::TX_DATA_ZERO_GAS
+            + non_zero_bytes * constants::TX_DATA_NON_ZERO_GAS;
+
+        if self.to().is_create() {
+            gas += constants::TX_CREATE_GAS;
+            gas += (input.len() as u64).div_ceil(32) * constants::INIT_CODE_WORD_GAS;
+        } else {
+            gas += constants::TX_GAS;
+        }
+
+        gas + self.access_list_gas()
+    }
+
+    /// Returns the intrinsic gas charged for the [EIP-2930] access list of the transaction, i.e.
+    /// 2400 gas per address and 1900 gas per storage key.
+    ///
+    /// This is `0` for transactions without an access list.
+    ///
+    /// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+    fn access_list_gas(&self) -> u64 {
+        self.access_list().map_or(0, |access_list| {
+            access_list
+                .iter()
+                .map(|item| {
+                    constants::ACCESS_LIST_ADDRESS_GAS
+                        + item.storage_keys.len() as u64 * constants::ACCESS_LIST_STORAGE_KEY_GAS
+                })
+                .sum()
+        })
+    }
+
+    /// Returns the calldata floor gas of the transaction, as defined in [EIP-7623].
+    ///
+    /// This is `21000 + 10 * tokens`, where each zero byte of [`input`](Self::input) counts as
+    /// one token and each non-zero byte as four. Under EIP-7623 the gas used by a transaction is
+    /// at least this value, so callers should take the maximum of this and the
+    /// [intrinsic gas](Self::intrinsic_gas).
+    ///
+    /// [EIP-7623]: https://eips.ethereum.org/EIPS/eip-7623
+    fn floor_data_gas(&self) -> u64 {
+        let input = self.input();
+        let zero_bytes = input.iter().filter(|byte| **byte == 0).count() as u64;
+        let non_zero_bytes = input.len() as u64 - zero_bytes;
+
+        let tokens = zero_bytes + non_zero_bytes * constants::TX_DATA_NON_ZERO_TOKENS;
+        constants::TX_GAS + tokens * constants::TX_DATA_FLOOR_TOKEN_GAS
+    }
+
+    /// Returns the L1 data gas of the [`input`](Self::input) of the transaction, with the given
+    /// costs per zero and non-zero byte, e.g. to estimate the data availability fee charged by a
+    /// rollup.
+    ///
+    /// Rollups use different costs, and usually charge for the whole signed transaction rather
+    /// than its input only: see [`l1_data_gas`](fn@l1_data_gas) to price arbitrary bytes, such
+    /// as the [EIP-2718] encoding of a signed transaction.
+    ///
+    /// [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+    fn l1_data_gas(&self, zero_byte_cost: u64, nonzero_byte_cost: u64) -> u64 {
+        l1_data_gas(self.input(), zero_byte_cost, nonzero_byte_cost)
+    }
+
+    /// Returns `true` if the gas limit of the transaction does not exceed the given block gas
+    /// limit, i.e. if the transaction can fit in such a block at all.
+    fn fits_in_block(&self, block_gas_limit: u64) -> bool {
+        self.gas_limit() <= block_gas_limit as u128
+    }
+
+    /// Performs sanity checks on the fee configuration of the transaction before broadcasting.
+    ///
+    /// This checks that the gas limit is non-zero and covers the
+    /// [intrinsic gas](Self::intrinsic_gas). For [EIP-1559] style transactions, this additionally
+    /// checks that the priority fee does not exceed the max fee and, if a `base_fee` is provided,
+    /// that the max fee covers it.
+    ///
+    /// [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+    fn validate_fees(&self, base_fee: Option<u64>) -> Result<(), FeeValidationError> {
+        let gas_limit = self.gas_limit();
+        if gas_limit == 0 {
+            return Err(FeeValidationError::ZeroGasLimit);
+        }
+
+        let intrinsic_gas = self.intrinsic_gas();
+        if gas_limit < intrinsic_gas as u128 {
+            return Err(FeeValidationError::GasLimitBelowIntrinsicGas { gas_limit, intrinsic_gas });
+        }
+
+        if let Some(max_priority_fee_per_gas) = self.max_priority_fee_per_gas() {
+            let max_fee_per_gas = self.max_fee_per_gas();
+            if max_priority_fee_per_gas > max_fee_per_gas {
+                return Err(FeeValidationError::PriorityFeeExceedsMaxFee {
+                    max_priority_fee_per_gas,
+                    max_fee_per_gas,
+                });
+            }
+
+            if let Some(base_fee) = base_fee {
+                if max_fee_per_gas < base_fee as u128 {
+                    return Err(FeeValidationError::MaxFeeBelowBaseFee {
+                        max_fee_per_gas,
+                        base_fee,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that every [blob versioned hash](Self::blob_versioned_hashes) starts with the
+    /// [KZG version byte](VERSIONED_HASH_VERSION_KZG), as required by [EIP-4844].
+    ///
+    /// Returns the first offending hash, if any. Transactions without blobs always pass.
+    ///
+    /// [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
+    fn validate_blob_versioned_hashes(&self) -> Result<(), BlobHashError> {
+        let Some(hashes) = self.blob_versioned_hashes() else { return Ok(()) };
+        for (index, hash) in hashes.iter().enumerate() {
+            if hash[0] != VERSIONED_HASH_VERSION_KZG {
+                return Err(BlobHashError { index, version: hash[0] });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns whether the [max fee per blob gas](Self::max_fee_per_blob_gas) covers the given
+    /// blob base fee, i.e. whether the transaction can be included in a block with that blob
+    /// base fee as far as the blob fee market is concerned.
+    ///
+    /// Returns `None` for transactions without blobs.
+    fn blob_fee_includable(&self, blob_base_fee: u128) -> Option<bool> {
+        self.max_fee_per_blob_gas()
+            .map(|max_fee_per_blob_gas| max_fee_per_blob_gas >= blob_base_fee)
+    }
+
+    /// Returns the maximum total fee the sender of an [EIP-4844] transaction may pay, i.e.
+    /// `gas_limit * max_fee_per_gas + blob_gas * max_fee_per_blob_gas`.
+    ///
+    /// This is the amount, excluding the transferred value, that the sender balance must cover.
+    /// Returns `None` for transactions without blobs, or if the computation overflows.
+    ///
+    /// [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
+    fn max_total_fee_4844(&self) -> Option<U256> {
+        let max_fee_per_blob_gas = self.max_fee_per_blob_gas()?;
+        let blob_gas = U256::from(self.blob_versioned_hashes()?.len())
+            .checked_mul(U256::from(DATA_GAS_PER_BLOB))?;
+        let execution_fee =
+            U256::from(self.gas_limit()).checked_mul(U256::from(self.max_fee_per_gas()))?;
+        execution_fee.checked_add(blob_gas.checked_mul(U256::from(max_fee_per_blob_gas))?)
+    }
+
+    /// Returns the effective gas price the transaction would pay for each of the given base fees.
+    ///
+    /// For [EIP-1559] style transactions this is `min(max_fee, base_fee + priority_fee)`, while
+    /// legacy and [EIP-2930] transactions pay their gas price regardless of the base fee.
+    ///
+    /// [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+    /// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+    fn effective_gas_price_curve(&self, base_fees: &[u64]) -> Vec<u128> {
+        let max_fee_per_gas = self.max_fee_per_gas();
+        let max_priority_fee_per_gas = self.max_priority_fee_per_gas();
+        base_fees
+            .iter()
+            .map(|&base_fee| {
+                max_priority_fee_per_gas.map_or(max_fee_per_gas, |priority_fee| {
+                    max_fee_per_gas.min((base_fee as u128).saturating_add(priority_fee))
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the priority fee per gas the transaction pays to the block producer at the given
+    /// base fee.
+    ///
+    /// For [EIP-1559] style transactions this is `min(max_priority_fee, max_fee - base_fee)`,
+    /// while legacy and [EIP-2930] transactions tip `gas_price - base_fee`. Returns `None` if the
+    /// fee cap of the transaction does not cover the base fee.
+    ///
+    /// [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+    /// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+    fn effective_tip_per_gas(&self, base_fee: u64) -> Option<u128> {
+        let fee = self.max_fee_per_gas().checked_sub(base_fee as u128)?;
+        Some(self.max_priority_fee_per_gas().map_or(fee, |priority_fee| priority_fee.min(fee)))
+    }
+
+    /// Returns the ABI-encoded `execute(address,uint256,bytes)` call forwarding this transaction's
+    /// [`to`](Self::to), [`value`](Self::value) and [`input`](Self::input), as used in the
+    /// `callData` of an [ERC-4337] user operation for smart accounts such as `SimpleAccount`.
+    ///
+    /// Returns `None` for contract creations, which cannot be expressed as such a call.
+    ///
+    /// [ERC-4337]: https://eips.ethereum.org/EIPS/eip-4337
+    fn to_user_op_calldata(&self) -> Option<Bytes> {
+        let to = self.to().to().copied()?;
+        let input = self.input();
+        let padded_len = input.len().div_ceil(32) * 32;
+
+        let mut calldata = Vec::with_capacity(4 + 4 * 32 + padded_len);
+        calldata.extend_from_slice(&USER_OP_EXECUTE_SELECTOR);
+        calldata.extend_from_slice(to.into_word().as_slice());
+        calldata.extend_from_slice(&self.value().to_be_bytes::<32>());
+        // offset of the dynamic `bytes` argument, right after the three head words
+        calldata.extend_from_slice(&U256::from(3 * 32).to_be_bytes::<32>());
+        calldata.extend_from_slice(&U256::from(input.len()).to_be_bytes::<32>());
+        calldata.extend_from_slice(input);
+        calldata.resize(4 + 4 * 32 + padded_len, 0);
+        Some(calldata.into())
+    }
+
+    /// Converts the transaction into a [`CallBundleEntry`] sent from `from`, as used to simulate
+    /// bundles of transactions with e.g. `eth_callBundle`.
+    fn to_call_bundle_entry(&self, from: Address) -> CallBundleEntry {
+        let max_priority_fee_per_gas = self.max_priority_fee_per_gas();
+        CallBundleEntry {
+            from,
+            to: self.to().to().copied(),
+            gas: self.gas_limit(),
+            value: self.value(),
+            data: Bytes::copy_from_slice(self.input()),
+            gas_price: self.gas_price(),
+            max_fee_per_gas: max_priority_fee_per_gas.map(|_| self.max_fee_per_gas()),
+            max_priority_fee_per_gas,
+        }
+    }
+
+    /// Converts the transaction into its [EIP-712] typed data representation.
+    ///
+    /// Contract creations use the zero address as recipient. Legacy and [EIP-2930] transactions
+    /// use their gas price as both the max fee and the max priority fee.
+    ///
+    /// [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+    /// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+    #[cfg(feature = "eip712")]
+    fn to_eip712_struct(&self) -> Eip712Transaction {
+        let max_fee_per_gas = self.max_fee_per_gas();
+        Eip712Transaction {
+            nonce: U256::from(self.nonce()),
+            to: self.to().to().copied().unwrap_or_default(),
+            value: self.value(),
+            data: Bytes::copy_from_slice(self.input()),
+            gasLimit: U256::from(self.gas_limit()),
+            maxFeePerGas: U256::from(max_fee_per_gas),
+            maxPriorityFeePerGas: U256::from(
+                self.max_priority_fee_per_gas().unwrap_or(max_fee_per_gas),
+            ),
+        }
+    }
+
+    /// Computes the [EIP-712] signing hash of the [typed data representation] of the transaction
+    /// under the given domain, i.e. `keccak256("\x19\x01" ‖ domainSeparator ‖ hashStruct(tx))`.
+    ///
+    /// [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+    /// [typed data representation]: Self::to_eip712_struct
+    #[cfg(feature = "eip712")]
+    fn to_eip712_struct_hash(&self, domain: &alloy_sol_types::Eip712Domain) -> B256 {
+        use alloy_sol_types::SolStruct;
+        self.to_eip712_struct().eip712_signing_hash(domain)
+    }
+
+    /// Serializes the transaction into the JSON shape of an RPC transaction request, as accepted
+    /// by e.g. `eth_call` and `eth_estimateGas`.
+    ///
+    /// All numeric fields are encoded as hex quantities and absent fields are omitted. Legacy and
+    /// [EIP-2930] transactions use `gasPrice`, while [EIP-1559] and [EIP-4844] transactions use
+    /// `maxFeePerGas` and `maxPriorityFeePerGas`.
+    ///
+    /// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+    /// [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+    /// [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
+    #[cfg(feature = "serde")]
+    fn to_request_json(&self, from: Option<alloy_primitives::Address>) -> serde_json::Value {
+        #[cfg(not(feature = "std"))]
+        use alloc::{format, string::ToString};
+        use alloy_primitives::hex;
+        use serde_json::{Map, Value};
+
+        fn quantity(value: impl core::fmt::LowerHex) -> Value {
+            Value::String(format!("{value:#x}"))
+        }
+
+        let mut request = Map::new();
+        if let Some(from) = from {
+            request.insert("from".into(), Value::String(from.to_string()));
+        }
+        if let TxKind::Call(to) = self.to() {
+            request.insert("to".into(), Value::String(to.to_string()));
+        }
+        request.insert("gas".into(), quantity(self.gas_limit()));
+        match self.max_priority_fee_per_gas() {
+            Some(max_priority_fee_per_gas) => {
+                request.insert("maxFeePerGas".into(), quantity(self.max_fee_per_gas()));
+                request.insert("maxPriorityFeePerGas".into(), quantity(max_priority_fee_per_gas));
+            }
+            None => {
+                request.insert("gasPrice".into(), quantity(self.max_fee_per_gas()));
+            }
+        }
+        if let Some(max_fee_per_blob_gas) = self.max_fee_per_blob_gas() {
+            request.insert("maxFeePerBlobGas".into(), quantity(max_fee_per_blob_gas));
+        }
+        request.insert("value".into(), quantity(self.value()));
+        request.insert("input".into(), Value::String(hex::encode_prefixed(self.input())));
+        request.insert("nonce".into(), quantity(self.nonce()));
+        if let Some(chain_id) = self.chain_id() {
+            request.insert("chainId".into(), quantity(chain_id));
+        }
+        if let Some(access_list) = self.access_list() {
+            request.insert(
+                "accessList".into(),
+                serde_json::to_value(access_list).expect("access list is serializable"),
+            );
+        }
+        if let Some(blob_versioned_hashes) = self.blob_versioned_hashes() {
+            request.insert(
+                "blobVersionedHashes".into(),
+                blob_versioned_hashes.iter().map(|hash| Value::String(hash.to_string())).collect(),
+            );
+        }
+        request.insert("type".into(), quantity(self.ty()));
+
+        Value::Object(request)
+    }
+
+    /// Serializes the transaction into a JSON object meant for human review, e.g. to show a user
+    /// what they are about to sign.
+    ///
+    /// Unlike [`to_request_json`](Self::to_request_json), fields are decoded:
+    /// - `to` is the checksummed recipient, or `"Contract Creation"`.
+    /// - `valueWei` and `valueEther` are the value in wei and in ether.
+    /// - `valueWei`, `gasLimit` and the fee fields are decimal strings. Fee fields are in gwei and
+    ///   have a `Gwei` suffix, e.g. `gasPriceGwei` or `maxFeePerGasGwei`.
+    /// - `dataLength` is the input length in bytes and `selector` the hex encoded [function
+    ///   selector](Self::selector), or `null`.
+    ///
+    /// The output is not meant to be parsed back into a transaction.
+    #[cfg(feature = "serde")]
+    fn to_display_json(&self) -> serde_json::Value {
+        #[cfg(not(feature = "std"))]
+        use alloc::string::ToString;
+        use alloy_primitives::{
+            hex,
+            utils::{format_ether, ParseUnits, Unit},
+        };
+        use serde_json::{Map, Value};
+
+        fn gwei(value: u128) -> Value {
+            Value::String(ParseUnits::from(value).format_units(Unit::GWEI))
+        }
+
+        let mut display = Map::new();
+        display.insert("type".into(), self.ty().into());
+        if let Some(chain_id) = self.chain_id() {
+            display.insert("chainId".into(), chain_id.into());
+        }
+        display.insert("nonce".into(), self.nonce().into());
+        display.insert(
+            "to".into(),
+            Value::String(match self.to() {
+                TxKind::Call(to) => to.to_checksum(None),
+                TxKind::Create => "Contract Creation".into(),
+            }),
+        );
+        display.insert("valueWei".into(), Value::String(self.value().to_string()));
+        display.insert("valueEther".into(), Value::String(format_ether(self.value())));
+        display.insert("gasLimit".into(), Value::String(self.gas_limit().to_string()));
+        match self.max_priority_fee_per_gas() {
+            Some(max_priority_fee_per_gas) => {
+                display.insert("maxFeePerGasGwei".into(), gwei(self.max_fee_per_gas()));
+                display.insert("maxPriorityFeePerGasGwei".into(), gwei(max_priority_fee_per_gas));
+            }
+            None => {
+                display.insert("gasPriceGwei".into(), gwei(self.max_fee_per_gas()));
+            }
+        }
+        if let Some(max_fee_per_blob_gas) = self.max_fee_per_blob_gas() {
+            display.insert("maxFeePerBlobGasGwei".into(), gwei(max_fee_per_blob_gas));
+        }
+        display.insert("dataLength".into(), self.input().len().into());
+        display.insert(
+            "selector".into(),
+            self.selector()
+                .map_or(Value::Null, |selector| Value::String(hex::encode_prefixed(selector))),
+        );
+
+        Value::Object(display)
+    }
+}
+
+/// Returns the total priority fee paid to the block producer by a transaction that used
+/// `gas_used` gas in a block with the given base fee, i.e. the
+/// [effective tip per gas](Transaction::effective_tip_per_gas) times the gas used.
+///
+/// Returns zero if the transaction cannot pay the base fee.
+pub fn total_priority_payment(tx: &impl Transaction, gas_used: u64, base_fee: u64) -> u128 {
+    tx.effective_tip_per_gas(base_fee).unwrap_or_default().saturating_mul(gas_used as u128)
+}
+
+/// Splits the fees paid for `gas_used` gas at the given effective gas price into the part burned
+/// by the base fee and the tip paid to the block producer, as defined by [EIP-1559].
+///
+/// Returns `(burned, tip)`, where `burned = gas_used * base_fee` and
+/// `tip = gas_used * (effective_gas_price - base_fee)`. The tip is zero if the effective gas price
+/// is below the base fee.
+///
+/// [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+pub const fn gas_revenue_split(
+    gas_used: u64,
+    effective_gas_price: u128,
+    base_fee: u64,
+) -> (u128, u128) {
+    let gas_used = gas_used as u128;
+    let burned = gas_used.saturating_mul(base_fee as u128);
+    let tip = gas_used.saturating_mul(effective_gas_price.saturating_sub(base_fee as u128));
+    (burned, tip)
+}
+
+/// Returns the L1 data gas of `data`, with the given costs per zero and non-zero byte,
+/// saturating at `u64::MAX`.
+///
+/// For example, Optimism before the Ecotone upgrade charged 4 gas per zero byte and 16 gas per
+/// non-zero byte of the signed transaction, plus a fixed overhead. See also
+/// [`Transaction::l1_data_gas`].
+pub fn l1_data_gas(data: &[u8], zero_byte_cost: u64, nonzero_byte_cost: u64) -> u64 {
+    let zero_bytes = data.iter().filter(|byte| **byte == 0).count() as u64;
+    let non_zero_bytes = data.len() as u64 - zero_bytes;
+    zero_bytes
+        .saturating_mul(zero_byte_cost)
+        .saturating_add(non_zero_bytes.saturating_mul(nonzero_byte_cost))
+}
+
+/// Returns `true` if `new` pays enough to replace `old` in a transaction pool, i.e. if its fees
+/// are at least `min_bump_percent` higher. Most nodes require a bump of at least 10%.
+///
+/// Both the max fee per gas and the max priority fee per gas must clear the bump. Transactions
+/// that predate [EIP-1559] use their gas price for both, so legacy and [EIP-1559] transactions
+/// can replace each other. The required fees are rounded up and saturate at `u128::MAX`, like
+/// [`TxEip1559::bump_fees`].
+///
+/// The max fee per blob gas is not compared, as nodes apply their own rules to replacement blob
+/// transactions. The nonces and senders are not compared either.
+///
+/// [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+pub fn replaces(new: &impl Transaction, old: &impl Transaction, min_bump_percent: u16) -> bool {
+    fn fees(tx: &impl Transaction) -> (u128, u128) {
+        let max_fee_per_gas = tx.max_fee_per_gas();
+        (max_fee_per_gas, tx.max_priority_fee_per_gas().unwrap_or(max_fee_per_gas))
+    }
+
+    let (new_max_fee, new_priority_fee) = fees(new);
+    let (old_max_fee, old_priority_fee) = fees(old);
+    new_max_fee >= fee_with_bump(old_max_fee, min_bump_percent)
+        && new_priority_fee >= fee_with_bump(old_priority_fee, min_bump_percent)
+}
+
+/// Error returned by [`Transaction::validate_fees`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeeValidationError {
+    /// The gas limit is zero.
+    ZeroGasLimit,
+    /// The gas limit does not cover the intrinsic gas of the transaction.
+    GasLimitBelowIntrinsicGas {
+        /// The gas limit of the transaction.
+        gas_limit: u128,
+        /// The intrinsic gas of the transaction.
+        intrinsic_gas: u64,
+    },
+    /// The max priority fee per gas is higher than the max fee per gas.
+    PriorityFeeExceedsMaxFee {
+        /// The max priority fee per gas of the transaction.
+        max_priority_fee_per_gas: u128,
+        /// The max fee per gas of the transaction.
+        max_fee_per_gas: u128,
+    },
+    /// The max fee per gas does not cover the base fee.
+    MaxFeeBelowBaseFee {
+        /// The max fee per gas of the transaction.
+        max_fee_per_gas: u128,
+        /// The base fee.
+        base_fee: u64,
+    },
 }
 
+impl fmt::Display for FeeValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroGasLimit => f.write_str("gas limit is zero"),
+            Self::GasLimitBelowIntrinsicGas { gas_limit, intrinsic_gas } => {
+                write!(f, "gas limit {gas_limit} is below the intrinsic gas {intrinsic_gas}")
+            }
+            Self::PriorityFeeExceedsMaxFee { max_priority_fee_per_gas, max_fee_per_gas } => write!(
+                f,
+                "max priority fee per gas {max_priority_fee_per_gas} exceeds max fee per gas \
+                 {max_fee_per_gas}"
+            ),
+            Self::MaxFeeBelowBaseFee { max_fee_per_gas, base_fee } => {
+                write!(f, "max fee per gas {max_fee_per_gas} is below the base fee {base_fee}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FeeValidationError {}
+
+/// Error returned by [`Transaction::validate_blob_versioned_hashes`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlobHashError {
+    /// The index of the offending hash in the blob versioned hashes.
+    pub index: usize,
+    /// The version byte of the offending hash.
+    pub version: u8,
+}
+
+impl fmt::Display for BlobHashError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "blob versioned hash {} has version {:#04x}, expected {:#04x}",
+            self.index, self.version, VERSIONED_HASH_VERSION_KZG
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BlobHashError {}
+
+/// Error returned by [`SignableTransaction::with_access_list`] for transaction types that
+/// cannot carry an [EIP-2930] access list, holding the unchanged transaction.
+///
+/// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccessListUnsupported<T>(pub T);
+
+impl<T> AccessListUnsupported<T> {
+    /// Consumes the error, returning the unchanged transaction.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Transaction> fmt::Display for AccessListUnsupported<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "transaction type {:#04x} does not support access lists", self.0.ty())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Transaction + fmt::Debug> std::error::Error for AccessListUnsupported<T> {}
+
+/// Error returned by [`SignableTransaction::apply_fee_suggestion`] for transaction types that
+/// predate the [EIP-1559] fee market, holding the unchanged transaction.
+///
+/// [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeeMarketUnsupported<T>(pub T);
+
+impl<T> FeeMarketUnsupported<T> {
+    /// Consumes the error, returning the unchanged transaction.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Transaction> fmt::Display for FeeMarketUnsupported<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "transaction type {:#04x} does not support EIP-1559 fees", self.0.ty())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Transaction + fmt::Debug> std::error::Error for FeeMarketUnsupported<T> {}
+
 /// A signable transaction.
 ///
 /// A transaction can have multiple signature types. This is usually
@@ -113,6 +856,74 @@ pub trait SignableTransaction<Signature>: Transaction {
         keccak256(self.encoded_for_signing())
     }
 
+    /// Calculates a fingerprint of the transaction body, i.e. the `keccak256` hash of its RLP
+    /// encoded fields without any list header, type byte or signature.
+    ///
+    /// Unlike the [signature hash](Self::signature_hash), this is not what gets signed: it omits
+    /// the [EIP-2718] type byte. The chain ID is always part of the fingerprint, through the
+    /// [EIP-155] signing fields for legacy transactions, so the same body on different chains
+    /// has different fingerprints. Two signed copies of the same transaction body, e.g. signed by
+    /// different keys, share a fingerprint.
+    ///
+    /// The default implementation falls back to the signature hash.
+    ///
+    /// [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+    /// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+    fn unsigned_fingerprint(&self) -> B256 {
+        self.signature_hash()
+    }
+
+    /// Normalizes the `input` of the transaction to its canonical form.
+    ///
+    /// The canonical form of an absent input is an empty [`Bytes`], which is the only
+    /// representation the transaction types of this crate have: RLP does not distinguish a
+    /// missing input from an empty one, so decoders producing either always end up with empty
+    /// `Bytes`. This is therefore a no-op for all transactions in this crate, and never
+    /// reallocates. It exists so that comparison code can normalize transactions of other
+    /// networks, which may override it.
+    fn normalize_input(&mut self) {}
+
+    /// Returns the transaction with its access list replaced by `access_list`, e.g. to attach an
+    /// access list computed by simulating the transaction.
+    ///
+    /// Transaction types that predate [EIP-2930] cannot carry an access list: for them, this
+    /// returns the unchanged transaction in an [`AccessListUnsupported`] error, which is the
+    /// default implementation.
+    ///
+    /// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+    fn with_access_list(self, _access_list: AccessList) -> Result<Self, AccessListUnsupported<Self>>
+    where
+        Self: Sized,
+    {
+        Err(AccessListUnsupported(self))
+    }
+
+    /// Returns the transaction with its max fee and max priority fee per gas replaced, e.g. to
+    /// apply the output of a fee estimator.
+    ///
+    /// Transaction types that predate [EIP-1559] only have a gas price: for them, this returns the
+    /// unchanged transaction in a [`FeeMarketUnsupported`] error, which is the default
+    /// implementation.
+    ///
+    /// [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+    fn apply_fee_suggestion(
+        self,
+        _max_fee_per_gas: u128,
+        _max_priority_fee_per_gas: u128,
+    ) -> Result<Self, FeeMarketUnsupported<Self>>
+    where
+        Self: Sized,
+    {
+        Err(FeeMarketUnsupported(self))
+    }
+
+    /// Increases the fees of the transaction by at least `percent`, e.g. to replace a pending
+    /// transaction with the same nonce.
+    ///
+    /// The transaction types of this crate bump all of their fees, see e.g.
+    /// [`TxEip1559::bump_fees`]. The default implementation leaves the transaction unchanged.
+    fn bump_fees(&mut self, _percent: u16) {}
+
     /// Convert to a signed transaction by adding a signature and computing the
     /// hash.
     fn into_signed(self, signature: Signature) -> Signed<Self, Signature>
@@ -131,3 +942,554 @@ impl<S: 'static> dyn SignableTransaction<S> {
         }
     }
 }
+
+/// The selector of `execute(address,uint256,bytes)`.
+const USER_OP_EXECUTE_SELECTOR: [u8; 4] = [0xb6, 0x1d, 0x27, 0xf6];
+
+/// Increases `fee` by `percent`, rounding up and saturating at `u128::MAX`.
+const fn fee_with_bump(fee: u128, percent: u16) -> u128 {
+    let percent = percent as u128;
+    // split the multiplication so it cannot overflow before the division
+    let bump =
+        (fee / 100).saturating_mul(percent).saturating_add((fee % 100 * percent).div_ceil(100));
+    fee.saturating_add(bump)
+}
+
+/// Increases `gas_limit` by `percent`, rounding down and saturating at `u128::MAX`.
+const fn gas_limit_with_margin(gas_limit: u128, percent: u16) -> u128 {
+    let percent = percent as u128;
+    // split the multiplication so it cannot overflow before the division
+    let margin =
+        (gas_limit / 100).saturating_mul(percent).saturating_add(gas_limit % 100 * percent / 100);
+    gas_limit.saturating_add(margin)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eips::eip2930::AccessListItem;
+    use alloy_primitives::{address, bytes, hex, Address, Signature};
+
+    #[test]
+    fn default_accessors() {
+        /// A transaction implementing only the required methods.
+        struct MinimalTx;
+
+        impl Transaction for MinimalTx {
+            fn chain_id(&self) -> Option<ChainId> {
+                Some(1)
+            }
+
+            fn nonce(&self) -> u64 {
+                0
+            }
+
+            fn gas_limit(&self) -> u128 {
+                21_000
+            }
+
+            fn gas_price(&self) -> Option<u128> {
+                Some(7)
+            }
+
+            fn to(&self) -> TxKind {
+                TxKind::Create
+            }
+
+            fn value(&self) -> U256 {
+                U256::ZERO
+            }
+
+            fn input(&self) -> &[u8] {
+                &[]
+            }
+        }
+
+        assert_eq!(MinimalTx.max_fee_per_gas(), 7);
+        assert_eq!(MinimalTx.max_priority_fee_per_gas(), None);
+        assert_eq!(MinimalTx.max_fee_per_blob_gas(), None);
+        assert_eq!(MinimalTx.access_list(), None);
+        assert_eq!(MinimalTx.blob_versioned_hashes(), None);
+        assert_eq!(MinimalTx.ty(), TxType::Legacy as u8);
+    }
+
+    #[test]
+    fn intrinsic_gas() {
+        let transfer = TxLegacy { to: Address::ZERO.into(), ..Default::default() };
+        assert_eq!(transfer.intrinsic_gas(), 21_000);
+
+        let call = TxEip1559 {
+            to: Address::ZERO.into(),
+            input: hex!("0001").into(),
+            access_list: AccessList(vec![AccessListItem {
+                address: Address::ZERO,
+                storage_keys: vec![B256::ZERO, B256::ZERO],
+            }]),
+            ..Default::default()
+        };
+        assert_eq!(call.intrinsic_gas(), 21_000 + 4 + 16 + 2_400 + 2 * 1_900);
+
+        let create =
+            TxLegacy { to: TxKind::Create, input: vec![1; 33].into(), ..Default::default() };
+        assert_eq!(create.intrinsic_gas(), 53_000 + 33 * 16 + 2 * 2);
+    }
+
+    #[test]
+    fn with_access_list() {
+        let access_list = AccessList(vec![
+            AccessListItem { address: Address::ZERO, storage_keys: vec![B256::ZERO; 2] },
+            AccessListItem { address: Address::repeat_byte(1), storage_keys: vec![] },
+        ]);
+        let tx = TxEip1559 { to: Address::ZERO.into(), ..Default::default() };
+        assert_eq!(tx.access_list_gas(), 0);
+
+        let tx =
+            SignableTransaction::<Signature>::with_access_list(tx, access_list.clone()).unwrap();
+        assert_eq!(tx.access_list, access_list);
+        assert_eq!(tx.access_list_gas(), 2 * 2_400 + 2 * 1_900);
+        assert_eq!(tx.intrinsic_gas(), 21_000 + tx.access_list_gas());
+
+        let tx = TxEip4844Variant::from(TxEip4844::default());
+        let tx =
+            SignableTransaction::<Signature>::with_access_list(tx, access_list.clone()).unwrap();
+        assert_eq!(tx.access_list(), Some(&access_list));
+
+        let legacy = TxLegacy { nonce: 1, ..Default::default() };
+        let err = SignableTransaction::<Signature>::with_access_list(legacy.clone(), access_list)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "transaction type 0x00 does not support access lists");
+        assert_eq!(err.into_inner(), legacy);
+        assert_eq!(legacy.access_list_gas(), 0);
+    }
+
+    #[test]
+    fn apply_fee_suggestion() {
+        let tx = TxEip1559 {
+            max_fee_per_gas: 10,
+            max_priority_fee_per_gas: 1,
+            to: Address::ZERO.into(),
+            ..Default::default()
+        };
+        let tx =
+            SignableTransaction::<Signature>::apply_fee_suggestion(tx, 30_000_000_000, 2).unwrap();
+        assert_eq!(tx.max_fee_per_gas(), 30_000_000_000);
+        assert_eq!(tx.max_priority_fee_per_gas(), Some(2));
+
+        let tx = TxEip4844Variant::from(TxEip4844::default());
+        let tx = SignableTransaction::<Signature>::apply_fee_suggestion(tx, 7, 3).unwrap();
+        assert_eq!(tx.max_fee_per_gas(), 7);
+        assert_eq!(tx.max_priority_fee_per_gas(), Some(3));
+
+        let legacy = TxLegacy { gas_price: 5, ..Default::default() };
+        let err = SignableTransaction::<Signature>::apply_fee_suggestion(legacy.clone(), 7, 3)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "transaction type 0x00 does not support EIP-1559 fees");
+        assert_eq!(err.into_inner(), legacy);
+    }
+
+    #[test]
+    fn l1_data_gas() {
+        use alloy_eips::eip2718::Encodable2718;
+
+        // transfer(address,uint256): 4 + 20 + 1 non-zero bytes and 12 + 31 zero bytes
+        let tx = TxEip1559 {
+            chain_id: 10,
+            to: address!("dac17f958d2ee523a2206206994597c13d831ec7").into(),
+            input: hex!("a9059cbb000000000000000000000000f0109fc8df283027b6285cc889f5aa624eac1f550000000000000000000000000000000000000000000000000000000000000001").into(),
+            ..Default::default()
+        };
+        assert_eq!(tx.l1_data_gas(4, 16), 43 * 4 + 25 * 16);
+        assert_eq!(tx.l1_data_gas(0, 0), 0);
+        assert_eq!(TxLegacy::default().l1_data_gas(4, 16), 0);
+
+        let signed: TxEnvelope = tx.into_signed(Signature::test_signature()).into();
+        let encoded = signed.encoded_2718();
+        let zero_bytes = encoded.iter().filter(|byte| **byte == 0).count() as u64;
+        assert_eq!(
+            super::l1_data_gas(&encoded, 4, 16),
+            zero_bytes * 4 + (encoded.len() as u64 - zero_bytes) * 16
+        );
+        assert_eq!(super::l1_data_gas(&[1, 0], u64::MAX, 1), u64::MAX);
+    }
+
+    #[test]
+    fn floor_data_gas() {
+        let transfer = TxLegacy { to: Address::ZERO.into(), ..Default::default() };
+        assert_eq!(transfer.floor_data_gas(), 21_000);
+
+        // 3 zero bytes and 2 non-zero bytes: 3 + 2 * 4 = 11 tokens
+        let call = TxEip1559 {
+            to: Address::ZERO.into(),
+            input: hex!("00ff0000ab").into(),
+            ..Default::default()
+        };
+        assert_eq!(call.floor_data_gas(), 21_000 + 110);
+        assert!(call.floor_data_gas() > call.intrinsic_gas());
+    }
+
+    #[test]
+    fn access_list_pairs() {
+        let a = address!("0000000000000000000000000000000000000001");
+        let b = address!("0000000000000000000000000000000000000002");
+        let tx = TxEip1559 {
+            access_list: AccessList(vec![
+                AccessListItem {
+                    address: a,
+                    storage_keys: vec![B256::with_last_byte(1), B256::with_last_byte(2)],
+                },
+                AccessListItem { address: Address::ZERO, storage_keys: vec![] },
+                AccessListItem {
+                    address: b,
+                    storage_keys: vec![
+                        B256::with_last_byte(3),
+                        B256::with_last_byte(4),
+                        B256::with_last_byte(5),
+                    ],
+                },
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(
+            tx.access_list_pairs(),
+            vec![
+                (a, B256::with_last_byte(1)),
+                (a, B256::with_last_byte(2)),
+                (b, B256::with_last_byte(3)),
+                (b, B256::with_last_byte(4)),
+                (b, B256::with_last_byte(5)),
+            ]
+        );
+
+        assert!(TxLegacy::default().access_list_pairs().is_empty());
+    }
+
+    #[test]
+    fn with_gas_margin() {
+        let tx = TxEip1559 { gas_limit: 21_000, ..Default::default() }.with_gas_margin(20);
+        assert_eq!(tx.gas_limit, 25_200);
+
+        let tx = TxLegacy { gas_limit: 21_001, ..Default::default() }.with_gas_margin(20);
+        assert_eq!(tx.gas_limit, 25_201);
+        assert_eq!(tx.clone().with_gas_margin(0).gas_limit, 25_201);
+
+        let tx = TxLegacy { gas_limit: u128::MAX - 1, ..Default::default() }.with_gas_margin(1);
+        assert_eq!(tx.gas_limit, u128::MAX);
+        assert_eq!(gas_limit_with_margin(u128::MAX / 2, u16::MAX), u128::MAX);
+    }
+
+    #[test]
+    fn fits_in_block() {
+        let tx = TxEip1559 { gas_limit: 30_000_001, ..Default::default() };
+        assert!(!tx.fits_in_block(30_000_000));
+
+        let tx = tx.clamp_gas_limit(30_000_000);
+        assert_eq!(tx.gas_limit, 30_000_000);
+        assert!(tx.fits_in_block(30_000_000));
+
+        let tx = TxLegacy { gas_limit: 21_000, ..Default::default() };
+        assert!(tx.fits_in_block(30_000_000));
+        assert_eq!(tx.clamp_gas_limit(30_000_000).gas_limit, 21_000);
+    }
+
+    #[test]
+    fn bump_fees() {
+        let mut tx = TxEip1559 {
+            max_fee_per_gas: 20_000_000_000,
+            max_priority_fee_per_gas: 1_000_000_001,
+            ..Default::default()
+        };
+        tx.bump_fees(10);
+        assert_eq!(tx.max_fee_per_gas, 22_000_000_000);
+        // rounded up so the bump is at least 10%
+        assert_eq!(tx.max_priority_fee_per_gas, 1_100_000_002);
+
+        let mut tx = TypedTransaction::Legacy(TxLegacy { gas_price: 7, ..Default::default() });
+        tx.bump_fees(10);
+        assert_eq!(tx.legacy().unwrap().gas_price, 8);
+
+        let mut tx = TxLegacy { gas_price: u128::MAX - 1, ..Default::default() };
+        tx.bump_fees(10);
+        assert_eq!(tx.gas_price, u128::MAX);
+    }
+
+    #[test]
+    fn replaces() {
+        let old = TxEip1559 {
+            max_fee_per_gas: 20_000_000_000,
+            max_priority_fee_per_gas: 1_000_000_000,
+            ..Default::default()
+        };
+        let mut new = old.clone();
+        new.bump_fees(10);
+        assert!(super::replaces(&new, &old, 10));
+        assert!(!super::replaces(&old, &old, 10));
+        assert!(super::replaces(&old, &old, 0));
+
+        // both fees must clear the bump
+        let priority_only = TxEip1559 { max_fee_per_gas: old.max_fee_per_gas, ..new.clone() };
+        assert!(!super::replaces(&priority_only, &old, 10));
+        let max_fee_only = TxEip1559 { max_priority_fee_per_gas: 1_099_999_999, ..new.clone() };
+        assert!(!super::replaces(&max_fee_only, &old, 10));
+
+        let legacy = TxLegacy { gas_price: 10, ..Default::default() };
+        assert!(super::replaces(&TxLegacy { gas_price: 11, ..legacy.clone() }, &legacy, 10));
+        assert!(!super::replaces(&TxLegacy { gas_price: 10, ..legacy.clone() }, &legacy, 10));
+
+        // a legacy gas price counts as both fees
+        let eip1559 = TxEip1559 { max_fee_per_gas: 11, max_priority_fee_per_gas: 11, ..old };
+        assert!(super::replaces(&eip1559, &legacy, 10));
+        let low_tip = TxEip1559 { max_priority_fee_per_gas: 1, ..eip1559 };
+        assert!(!super::replaces(&low_tip, &legacy, 10));
+    }
+
+    #[test]
+    fn validate_fees() {
+        let tx = TxEip1559 {
+            gas_limit: 21_000,
+            max_fee_per_gas: 100,
+            max_priority_fee_per_gas: 10,
+            to: Address::ZERO.into(),
+            ..Default::default()
+        };
+        assert_eq!(tx.validate_fees(Some(50)), Ok(()));
+
+        assert_eq!(
+            TxEip1559 { gas_limit: 0, ..tx.clone() }.validate_fees(None),
+            Err(FeeValidationError::ZeroGasLimit)
+        );
+        assert_eq!(
+            TxEip1559 { gas_limit: 20_000, ..tx.clone() }.validate_fees(None),
+            Err(FeeValidationError::GasLimitBelowIntrinsicGas {
+                gas_limit: 20_000,
+                intrinsic_gas: 21_000
+            })
+        );
+        assert_eq!(
+            TxEip1559 { max_priority_fee_per_gas: 101, ..tx.clone() }.validate_fees(None),
+            Err(FeeValidationError::PriorityFeeExceedsMaxFee {
+                max_priority_fee_per_gas: 101,
+                max_fee_per_gas: 100
+            })
+        );
+        assert_eq!(
+            tx.validate_fees(Some(101)),
+            Err(FeeValidationError::MaxFeeBelowBaseFee { max_fee_per_gas: 100, base_fee: 101 })
+        );
+
+        // only the gas limit checks apply to legacy transactions
+        let legacy = TxLegacy {
+            gas_limit: 21_000,
+            gas_price: 1,
+            to: Address::ZERO.into(),
+            ..Default::default()
+        };
+        assert_eq!(legacy.validate_fees(Some(100)), Ok(()));
+        assert_eq!(
+            TxLegacy { gas_limit: 0, ..legacy }.validate_fees(None),
+            Err(FeeValidationError::ZeroGasLimit)
+        );
+    }
+
+    #[test]
+    fn has_input() {
+        let transfer = TxEip1559 {
+            to: address!("dac17f958d2ee523a2206206994597c13d831ec7").into(),
+            value: U256::from(1),
+            ..Default::default()
+        };
+        assert!(!transfer.has_input());
+
+        let mut call = TxEip1559 { input: hex!("a9059cbb").into(), ..transfer };
+        assert!(call.has_input());
+        let input = call.input.clone();
+        SignableTransaction::<Signature>::normalize_input(&mut call);
+        assert_eq!(call.input, input);
+    }
+
+    #[test]
+    fn selector() {
+        // transfer(address,uint256)
+        let tx = TxLegacy {
+            to: address!("dac17f958d2ee523a2206206994597c13d831ec7").into(),
+            input: hex!("a9059cbb000000000000000000000000f0109fc8df283027b6285cc889f5aa624eac1f550000000000000000000000000000000000000000000000000000000000000001").into(),
+            ..Default::default()
+        };
+        assert_eq!(tx.selector(), Some(hex!("a9059cbb")));
+        assert_eq!(tx.input_args().len(), 64);
+        assert_eq!(tx.input_args(), &tx.input[4..]);
+
+        let short = TxLegacy { input: hex!("a9059c").into(), ..tx.clone() };
+        assert_eq!(short.selector(), None);
+        assert!(short.input_args().is_empty());
+
+        let create = TxLegacy { to: TxKind::Create, ..tx };
+        assert_eq!(create.selector(), None);
+        assert!(create.input_args().is_empty());
+    }
+
+    #[test]
+    fn to_user_op_calldata() {
+        alloy_sol_types::sol! {
+            function execute(address dest, uint256 value, bytes func);
+        }
+        use alloy_sol_types::SolCall;
+
+        let to = address!("6069a6c32cf691f5982febae4faf8a6f3ab2f0f6");
+        let input =
+            bytes!("a9059cbb000000000000000000000000000000000000000000000000000000000000dead01");
+        let tx = TxEip1559 {
+            to: to.into(),
+            value: U256::from(1_000),
+            input: input.clone(),
+            ..Default::default()
+        };
+
+        let calldata = tx.to_user_op_calldata().unwrap();
+        assert_eq!(calldata[..4], executeCall::SELECTOR);
+        assert_eq!(calldata[..4], keccak256("execute(address,uint256,bytes)")[..4]);
+        assert_eq!(
+            calldata,
+            executeCall { dest: to, value: U256::from(1_000), func: input }.abi_encode()
+        );
+
+        let empty = TxLegacy { to: to.into(), ..Default::default() };
+        assert_eq!(
+            empty.to_user_op_calldata().unwrap(),
+            executeCall { dest: to, value: U256::ZERO, func: Bytes::new() }.abi_encode()
+        );
+
+        let create = TxLegacy { to: TxKind::Create, ..Default::default() };
+        assert_eq!(create.to_user_op_calldata(), None);
+    }
+
+    #[test]
+    fn blob_fee_includable() {
+        let tx = TxEip4844 { max_fee_per_blob_gas: 10, ..Default::default() };
+        assert_eq!(tx.blob_fee_includable(9), Some(true));
+        assert_eq!(tx.blob_fee_includable(10), Some(true));
+        assert_eq!(tx.blob_fee_includable(11), Some(false));
+
+        assert_eq!(TxEip1559::default().blob_fee_includable(0), None);
+    }
+
+    #[test]
+    fn max_total_fee_4844() {
+        let tx = TxEip4844 {
+            gas_limit: 21_000,
+            max_fee_per_gas: 100,
+            max_fee_per_blob_gas: 7,
+            blob_versioned_hashes: vec![B256::ZERO; 2],
+            ..Default::default()
+        };
+        assert_eq!(
+            tx.max_total_fee_4844(),
+            Some(U256::from(21_000 * 100 + 2 * DATA_GAS_PER_BLOB as u128 * 7))
+        );
+
+        let large = TxEip4844 { gas_limit: u128::MAX, max_fee_per_gas: 2, ..tx.clone() };
+        assert_eq!(
+            large.max_total_fee_4844(),
+            Some(U256::from(u128::MAX) * U256::from(2) + U256::from(2 * DATA_GAS_PER_BLOB * 7))
+        );
+
+        let overflow = TxEip4844 {
+            gas_limit: u128::MAX,
+            max_fee_per_gas: u128::MAX,
+            max_fee_per_blob_gas: u128::MAX,
+            ..tx
+        };
+        assert_eq!(overflow.max_total_fee_4844(), None);
+
+        assert_eq!(TxEip1559::default().max_total_fee_4844(), None);
+    }
+
+    #[test]
+    fn effective_gas_price_curve() {
+        let tx =
+            TxEip1559 { max_fee_per_gas: 100, max_priority_fee_per_gas: 10, ..Default::default() };
+        assert_eq!(tx.effective_gas_price_curve(&[50, 90, 120]), vec![60, 100, 100]);
+
+        let legacy = TxLegacy { gas_price: 42, ..Default::default() };
+        assert_eq!(legacy.effective_gas_price_curve(&[1, 100]), vec![42, 42]);
+        assert!(legacy.effective_gas_price_curve(&[]).is_empty());
+    }
+
+    #[test]
+    fn effective_tip_per_gas() {
+        let tx =
+            TxEip1559 { max_fee_per_gas: 100, max_priority_fee_per_gas: 10, ..Default::default() };
+        assert_eq!(tx.effective_tip_per_gas(50), Some(10));
+        assert_eq!(tx.effective_tip_per_gas(95), Some(5));
+        assert_eq!(tx.effective_tip_per_gas(101), None);
+
+        let legacy = TxLegacy { gas_price: 42, ..Default::default() };
+        assert_eq!(legacy.effective_tip_per_gas(40), Some(2));
+    }
+
+    #[test]
+    fn total_priority_payment() {
+        let tx =
+            TxEip1559 { max_fee_per_gas: 100, max_priority_fee_per_gas: 10, ..Default::default() };
+        assert_eq!(super::total_priority_payment(&tx, 21_000, 50), 210_000);
+        assert_eq!(super::total_priority_payment(&tx, 21_000, 95), 105_000);
+        assert_eq!(super::total_priority_payment(&tx, 21_000, 101), 0);
+    }
+
+    #[test]
+    fn gas_revenue_split() {
+        assert_eq!(super::gas_revenue_split(21_000, 15, 10), (210_000, 105_000));
+        assert_eq!(super::gas_revenue_split(21_000, 10, 10), (210_000, 0));
+        assert_eq!(super::gas_revenue_split(0, 15, 10), (0, 0));
+    }
+
+    #[test]
+    fn validate_blob_versioned_hashes() {
+        let valid = alloy_eips::eip4844::kzg_to_versioned_hash(&[0; 48]);
+        let tx = TxEip4844 { blob_versioned_hashes: vec![valid, valid], ..Default::default() };
+        assert_eq!(tx.validate_blob_versioned_hashes(), Ok(()));
+
+        let mut invalid = valid;
+        invalid[0] = 0x02;
+        let tx = TxEip4844 { blob_versioned_hashes: vec![valid, invalid, invalid], ..tx };
+        assert_eq!(
+            tx.validate_blob_versioned_hashes(),
+            Err(BlobHashError { index: 1, version: 0x02 })
+        );
+
+        assert_eq!(TxLegacy::default().validate_blob_versioned_hashes(), Ok(()));
+    }
+
+    #[test]
+    fn ordering_key() {
+        let legacy =
+            TxLegacy { gas_price: 5, nonce: 1, to: Address::ZERO.into(), ..Default::default() };
+        let create = TxLegacy { to: TxKind::Create, ..legacy.clone() };
+        let eip1559 = TxEip1559 {
+            max_fee_per_gas: 100,
+            max_priority_fee_per_gas: 5,
+            nonce: 0,
+            to: Address::ZERO.into(),
+            ..Default::default()
+        };
+        let cheap = TxEip1559 { max_priority_fee_per_gas: 1, nonce: 7, ..eip1559.clone() };
+        assert_eq!(eip1559.ordering_key(), (5, 0, Some(Address::ZERO)));
+        assert_eq!(create.ordering_key(), (5, 1, None));
+
+        let mut keys = vec![
+            legacy.ordering_key(),
+            cheap.ordering_key(),
+            create.ordering_key(),
+            eip1559.ordering_key(),
+        ];
+        keys.sort();
+        assert_eq!(
+            keys,
+            vec![
+                cheap.ordering_key(),
+                eip1559.ordering_key(),
+                create.ordering_key(),
+                legacy.ordering_key()
+            ]
+        );
+    }
+}
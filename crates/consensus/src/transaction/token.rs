@@ -0,0 +1,155 @@
+use alloy_primitives::{Address, U256};
+
+/// An [ERC-20] token operation decoded from the calldata of a transaction.
+///
+/// See [`Transaction::token_operation`](crate::Transaction::token_operation).
+///
+/// [ERC-20]: https://eips.ethereum.org/EIPS/eip-20
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TokenOp {
+    /// `transfer(address to, uint256 amount)`, selector `0xa9059cbb`.
+    Transfer {
+        /// The recipient of the tokens.
+        to: Address,
+        /// The amount of tokens.
+        amount: U256,
+    },
+    /// `transferFrom(address from, address to, uint256 amount)`, selector `0x23b872dd`.
+    TransferFrom {
+        /// The owner of the tokens.
+        from: Address,
+        /// The recipient of the tokens.
+        to: Address,
+        /// The amount of tokens.
+        amount: U256,
+    },
+    /// `approve(address spender, uint256 amount)`, selector `0x095ea7b3`.
+    Approve {
+        /// The account allowed to spend the tokens.
+        spender: Address,
+        /// The allowance.
+        amount: U256,
+    },
+}
+
+impl TokenOp {
+    /// The selector of `transfer(address,uint256)`.
+    pub const TRANSFER_SELECTOR: [u8; 4] = [0xa9, 0x05, 0x9c, 0xbb];
+    /// The selector of `transferFrom(address,address,uint256)`.
+    pub const TRANSFER_FROM_SELECTOR: [u8; 4] = [0x23, 0xb8, 0x72, 0xdd];
+    /// The selector of `approve(address,uint256)`.
+    pub const APPROVE_SELECTOR: [u8; 4] = [0x09, 0x5e, 0xa7, 0xb3];
+
+    /// Decodes the ABI encoded arguments of the function with the given selector.
+    ///
+    /// Like Solidity, extra trailing calldata is ignored. Returns `None` for unknown selectors,
+    /// missing arguments and addresses with dirty upper bytes.
+    pub(crate) fn decode(selector: [u8; 4], args: &[u8]) -> Option<Self> {
+        let word = |index: usize| args.get(index * 32..(index + 1) * 32);
+        let address = |index: usize| {
+            let word = word(index)?;
+            word[..12].iter().all(|byte| *byte == 0).then(|| Address::from_slice(&word[12..]))
+        };
+        let uint = |index: usize| word(index).map(U256::from_be_slice);
+
+        match selector {
+            Self::TRANSFER_SELECTOR => Some(Self::Transfer { to: address(0)?, amount: uint(1)? }),
+            Self::TRANSFER_FROM_SELECTOR => {
+                Some(Self::TransferFrom { from: address(0)?, to: address(1)?, amount: uint(2)? })
+            }
+            Self::APPROVE_SELECTOR => {
+                Some(Self::Approve { spender: address(0)?, amount: uint(1)? })
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TokenOp;
+    use crate::{Transaction, TxEip1559, TxLegacy};
+    use alloy_primitives::{address, hex, keccak256, TxKind, U256};
+
+    #[test]
+    fn selectors() {
+        for (signature, selector) in [
+            ("transfer(address,uint256)", TokenOp::TRANSFER_SELECTOR),
+            ("transferFrom(address,address,uint256)", TokenOp::TRANSFER_FROM_SELECTOR),
+            ("approve(address,uint256)", TokenOp::APPROVE_SELECTOR),
+        ] {
+            assert_eq!(keccak256(signature)[..4], selector);
+        }
+    }
+
+    #[test]
+    fn token_operation() {
+        // USDT transfer of 250 USDT
+        let tx = TxEip1559 {
+            to: address!("dac17f958d2ee523a2206206994597c13d831ec7").into(),
+            input: hex!("a9059cbb00000000000000000000000028c6c06298d514db089934071355e5743bf21d60000000000000000000000000000000000000000000000000000000000ee6b280").into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            tx.token_operation(),
+            Some(TokenOp::Transfer {
+                to: address!("28c6c06298d514db089934071355e5743bf21d60"),
+                amount: U256::from(250_000_000),
+            })
+        );
+
+        let transfer_from = TxLegacy {
+            to: tx.to,
+            input: hex!("23b872dd000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000220000000000000000000000000000000000000000000000000000000000000005").into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            transfer_from.token_operation(),
+            Some(TokenOp::TransferFrom {
+                from: address!("0000000000000000000000000000000000000011"),
+                to: address!("0000000000000000000000000000000000000022"),
+                amount: U256::from(5),
+            })
+        );
+
+        let approve = TxLegacy {
+            input: hex!("095ea7b30000000000000000000000000000000000000000000000000000000000000011ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff").into(),
+            ..transfer_from.clone()
+        };
+        assert_eq!(
+            approve.token_operation(),
+            Some(TokenOp::Approve {
+                spender: address!("0000000000000000000000000000000000000011"),
+                amount: U256::MAX,
+            })
+        );
+    }
+
+    #[test]
+    fn not_a_token_operation() {
+        // WETH deposit()
+        let deposit = TxLegacy {
+            to: address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2").into(),
+            value: U256::from(1),
+            input: hex!("d0e30db0").into(),
+            ..Default::default()
+        };
+        assert_eq!(deposit.token_operation(), None);
+
+        let plain = TxLegacy { input: Default::default(), ..deposit.clone() };
+        assert_eq!(plain.token_operation(), None);
+
+        let transfer = hex!("a9059cbb00000000000000000000000028c6c06298d514db089934071355e5743bf21d60000000000000000000000000000000000000000000000000000000000ee6b280");
+        // missing amount
+        let truncated = TxLegacy { input: transfer[..4 + 32].to_vec().into(), ..deposit.clone() };
+        assert_eq!(truncated.token_operation(), None);
+        // dirty address
+        let mut dirty = transfer;
+        dirty[4] = 1;
+        let dirty = TxLegacy { input: dirty.into(), ..deposit.clone() };
+        assert_eq!(dirty.token_operation(), None);
+        // contract creation
+        let create = TxLegacy { to: TxKind::Create, input: transfer.into(), ..deposit };
+        assert_eq!(create.token_operation(), None);
+    }
+}
@@ -2,7 +2,8 @@ use crate::{
     transaction::eip4844::{TxEip4844, TxEip4844Variant, TxEip4844WithSidecar},
     Transaction, TxEip1559, TxEip2930, TxEnvelope, TxLegacy, TxType,
 };
-use alloy_primitives::{ChainId, TxKind};
+use alloy_eips::eip2930::AccessList;
+use alloy_primitives::{ChainId, TxKind, B256};
 
 /// The TypedTransaction enum represents all Ethereum transaction request types.
 ///
@@ -89,6 +90,24 @@ impl TypedTransaction {
         }
     }
 
+    /// Increases the fees of the transaction by at least `percent`, e.g. to replace a pending
+    /// transaction with the same nonce.
+    ///
+    /// This bumps the gas price of legacy and [EIP-2930] transactions, and the max fee and max
+    /// priority fee per gas of [EIP-1559] and [EIP-4844] transactions.
+    ///
+    /// [EIP-2930]: https://eips.ethereum.org/EIPS/eip-2930
+    /// [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+    /// [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
+    pub fn bump_fees(&mut self, percent: u16) {
+        match self {
+            Self::Legacy(tx) => tx.bump_fees(percent),
+            Self::Eip2930(tx) => tx.bump_fees(percent),
+            Self::Eip1559(tx) => tx.bump_fees(percent),
+            Self::Eip4844(tx) => tx.bump_fees(percent),
+        }
+    }
+
     /// Return the inner legacy transaction if it exists.
     pub const fn legacy(&self) -> Option<&TxLegacy> {
         match self {
@@ -177,6 +196,60 @@ impl Transaction for TypedTransaction {
             Self::Eip4844(tx) => tx.input(),
         }
     }
+
+    fn max_fee_per_gas(&self) -> u128 {
+        match self {
+            Self::Legacy(tx) => tx.max_fee_per_gas(),
+            Self::Eip2930(tx) => tx.max_fee_per_gas(),
+            Self::Eip1559(tx) => tx.max_fee_per_gas(),
+            Self::Eip4844(tx) => tx.max_fee_per_gas(),
+        }
+    }
+
+    fn max_priority_fee_per_gas(&self) -> Option<u128> {
+        match self {
+            Self::Legacy(tx) => tx.max_priority_fee_per_gas(),
+            Self::Eip2930(tx) => tx.max_priority_fee_per_gas(),
+            Self::Eip1559(tx) => tx.max_priority_fee_per_gas(),
+            Self::Eip4844(tx) => tx.max_priority_fee_per_gas(),
+        }
+    }
+
+    fn max_fee_per_blob_gas(&self) -> Option<u128> {
+        match self {
+            Self::Legacy(tx) => tx.max_fee_per_blob_gas(),
+            Self::Eip2930(tx) => tx.max_fee_per_blob_gas(),
+            Self::Eip1559(tx) => tx.max_fee_per_blob_gas(),
+            Self::Eip4844(tx) => tx.max_fee_per_blob_gas(),
+        }
+    }
+
+    fn ty(&self) -> u8 {
+        match self {
+            Self::Legacy(tx) => tx.ty(),
+            Self::Eip2930(tx) => tx.ty(),
+            Self::Eip1559(tx) => tx.ty(),
+            Self::Eip4844(tx) => tx.ty(),
+        }
+    }
+
+    fn access_list(&self) -> Option<&AccessList> {
+        match self {
+            Self::Legacy(tx) => tx.access_list(),
+            Self::Eip2930(tx) => tx.access_list(),
+            Self::Eip1559(tx) => tx.access_list(),
+            Self::Eip4844(tx) => tx.access_list(),
+        }
+    }
+
+    fn blob_versioned_hashes(&self) -> Option<&[B256]> {
+        match self {
+            Self::Legacy(tx) => tx.blob_versioned_hashes(),
+            Self::Eip2930(tx) => tx.blob_versioned_hashes(),
+            Self::Eip1559(tx) => tx.blob_versioned_hashes(),
+            Self::Eip4844(tx) => tx.blob_versioned_hashes(),
+        }
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -14,6 +14,67 @@ pub enum LocalSignerError {
     /// [`std::io`] error.
     #[error(transparent)]
     IoError(#[from] std::io::Error),
+    /// No address matching the vanity prefix was found.
+    #[error("no address matching the vanity prefix was found in {attempts} attempts")]
+    VanityNotFound {
+        /// The number of keys that were generated.
+        attempts: usize,
+    },
+    /// The private key is not a valid secp256k1 scalar.
+    #[error("invalid private key: {reason}")]
+    InvalidScalar {
+        /// Why the key is invalid.
+        reason: &'static str,
+    },
+    /// The environment variable is not set.
+    #[error("environment variable `{name}` is not set")]
+    EnvVarNotPresent {
+        /// The name of the environment variable.
+        name: String,
+    },
+    /// The environment variable is not valid unicode.
+    ///
+    /// The value is not included, as it may be a secret.
+    #[error("environment variable `{name}` is not valid unicode")]
+    EnvVarNotUnicode {
+        /// The name of the environment variable.
+        name: String,
+    },
+    /// The chain is not part of the [`WalletProfile`](crate::WalletProfile).
+    #[error("chain {chain_id} is not supported by the wallet profile")]
+    UnsupportedChain {
+        /// The requested chain ID.
+        chain_id: alloy_primitives::ChainId,
+    },
+    /// The transaction cannot be converted to the [`TxForm`](crate::TxForm) of the chain.
+    #[error("{tx_type} transactions cannot be converted to {form:?} transactions")]
+    IncompatibleTxForm {
+        /// The type of the transaction.
+        tx_type: alloy_consensus::TxType,
+        /// The requested transaction form.
+        form: crate::TxForm,
+    },
+    /// The value of the transaction exceeds the maximum value of the signer, see
+    /// [`LocalSigner::with_max_value`](crate::LocalSigner::with_max_value).
+    #[error("transaction value {value} exceeds the limit of {max}")]
+    ValueExceedsLimit {
+        /// The value of the transaction.
+        value: alloy_primitives::U256,
+        /// The maximum value of the signer.
+        max: alloy_primitives::U256,
+    },
+    /// Signing opaque hashes is disabled, see
+    /// [`LocalSigner::allow_blind_signing`](crate::LocalSigner::allow_blind_signing).
+    #[error("blind signing is disabled")]
+    BlindSigningDisabled,
+    /// A signing operation failed.
+    #[error("{context}: {source}")]
+    SigningError {
+        /// The caller-provided context of the operation.
+        context: String,
+        /// The underlying error.
+        source: alloy_signer::Error,
+    },
 
     /// [`coins_bip32`] error.
     #[error(transparent)]
@@ -27,9 +88,33 @@ pub enum LocalSignerError {
     #[error(transparent)]
     #[cfg(feature = "mnemonic")]
     MnemonicBuilderError(#[from] super::mnemonic::MnemonicBuilderError),
+    /// The account scan derived its maximum number of accounts without reaching the gap limit,
+    /// see [`LocalSigner::scan_accounts`](crate::LocalSigner::scan_accounts).
+    #[error("account scan stopped after {max_accounts} accounts without reaching the gap limit")]
+    #[cfg(feature = "mnemonic")]
+    AccountScanLimitReached {
+        /// The maximum number of derived accounts.
+        max_accounts: u32,
+    },
 
     /// [`eth_keystore`] error.
     #[cfg(feature = "keystore")]
     #[error(transparent)]
     EthKeystoreError(#[from] eth_keystore::KeystoreError),
+    /// The PBKDF2 iteration count of a keystore is zero.
+    #[cfg(feature = "keystore")]
+    #[error("the PBKDF2 iteration count must be non-zero")]
+    ZeroIterations,
+}
+
+impl LocalSignerError {
+    /// Returns the caller-provided context of the error, if any.
+    ///
+    /// See [`LocalSigner::sign_hash_ctx_sync`](crate::LocalSigner::sign_hash_ctx_sync).
+    pub fn context(&self) -> Option<&str> {
+        match self {
+            Self::SigningError { context, .. } => Some(context),
+            _ => None,
+        }
+    }
 }
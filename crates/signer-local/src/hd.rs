@@ -0,0 +1,124 @@
+//! A [BIP-32] hierarchical deterministic wallet caching its derived keys.
+//!
+//! [BIP-32]: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki
+
+use crate::{LocalSignerError, PrivateKeySigner};
+use alloy_primitives::{Signature, B256};
+use alloy_signer::{derivation::DerivationPath, Result, SignerSync};
+use coins_bip32::prelude::XPriv;
+use coins_bip39::{English, Mnemonic};
+use k256::ecdsa::SigningKey;
+use std::{collections::HashMap, fmt, sync::Mutex};
+
+/// A [BIP-32] wallet holding a master key, which signs with the keys derived at any
+/// [derivation path](DerivationPath).
+///
+/// Derived keys are cached by path, so only the first signature at a path pays for the chain of
+/// HMAC-SHA512 derivations, e.g. when serving many accounts of the same mnemonic.
+///
+/// [BIP-32]: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki
+pub struct HdWallet {
+    master_key: XPriv,
+    cache: Mutex<HashMap<Vec<u32>, PrivateKeySigner>>,
+}
+
+impl fmt::Debug for HdWallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HdWallet").field("cached_paths", &self.cached_paths()).finish()
+    }
+}
+
+impl HdWallet {
+    /// Creates a new wallet from an [English] [BIP-39] mnemonic phrase and an optional password.
+    ///
+    /// [BIP-39]: https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki
+    pub fn from_phrase(phrase: &str, password: Option<&str>) -> Result<Self, LocalSignerError> {
+        let master_key = Mnemonic::<English>::new_from_phrase(phrase)?.master_key(password)?;
+        Ok(Self { master_key, cache: Mutex::default() })
+    }
+
+    /// Returns the signer at the given derivation path, deriving and caching its key if this is
+    /// the first use of the path.
+    ///
+    /// The returned signer has no chain ID.
+    pub fn signer_at(&self, path: &DerivationPath) -> Result<PrivateKeySigner, LocalSignerError> {
+        self.signer_at_with(path, |indices| self.derive(indices))
+    }
+
+    fn signer_at_with(
+        &self,
+        path: &DerivationPath,
+        derive: impl FnOnce(&[u32]) -> Result<PrivateKeySigner, LocalSignerError>,
+    ) -> Result<PrivateKeySigner, LocalSignerError> {
+        let mut cache = self.cache.lock().unwrap_or_else(|err| err.into_inner());
+        if let Some(signer) = cache.get(path.indices()) {
+            return Ok(signer.clone());
+        }
+
+        let signer = derive(path.indices())?;
+        cache.insert(path.indices().to_vec(), signer.clone());
+        Ok(signer)
+    }
+
+    fn derive(&self, indices: &[u32]) -> Result<PrivateKeySigner, LocalSignerError> {
+        let derived = self.master_key.derive_path(indices)?;
+        let derived: &coins_bip32::prelude::SigningKey = derived.as_ref();
+        Ok(PrivateKeySigner::from_signing_key(SigningKey::from_bytes(&derived.to_bytes())?))
+    }
+
+    /// Signs the hash with the key at the given derivation path, like
+    /// [`sign_hash_sync`](SignerSync::sign_hash_sync).
+    ///
+    /// See [`signer_at`](Self::signer_at) for the caching of derived keys.
+    pub fn sign_hash_at_sync(&self, path: &DerivationPath, hash: &B256) -> Result<Signature> {
+        self.signer_at(path).map_err(alloy_signer::Error::other)?.sign_hash_sync(hash)
+    }
+
+    /// Returns the number of derivation paths whose key is cached.
+    pub fn cached_paths(&self) -> usize {
+        self.cache.lock().unwrap_or_else(|err| err.into_inner()).len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MnemonicBuilder;
+    use std::cell::Cell;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn sign_hash_at() {
+        let wallet = HdWallet::from_phrase(PHRASE, None).unwrap();
+        let first: DerivationPath = "m/44'/60'/0'/0/0".parse().unwrap();
+        let second: DerivationPath = "m/44'/60'/0'/0/1".parse().unwrap();
+        let hash = B256::repeat_byte(0x42);
+
+        let derivations = Cell::new(0);
+        let derive = |indices: &[u32]| {
+            derivations.set(derivations.get() + 1);
+            wallet.derive(indices)
+        };
+        for _ in 0..2 {
+            for (index, path) in [&first, &second].into_iter().enumerate() {
+                let expected = MnemonicBuilder::<English>::default()
+                    .phrase(PHRASE)
+                    .index(index as u32)
+                    .unwrap()
+                    .build()
+                    .unwrap();
+                assert_eq!(wallet.signer_at_with(path, derive).unwrap(), expected);
+
+                let signature = wallet.sign_hash_at_sync(path, &hash).unwrap();
+                assert_eq!(
+                    signature.recover_address_from_prehash(&hash).unwrap(),
+                    expected.address
+                );
+            }
+        }
+
+        assert_eq!(derivations.get(), 2);
+        assert_eq!(wallet.cached_paths(), 2);
+    }
+}
@@ -6,23 +6,44 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
-use alloy_consensus::SignableTransaction;
-use alloy_network::{TxSigner, TxSignerSync};
-use alloy_primitives::{Address, ChainId, Signature, B256};
-use alloy_signer::{sign_transaction_with_chain_id, Result, Signer, SignerSync};
+use alloy_consensus::{SignableTransaction, Signed, TxEnvelope};
+use alloy_network::{eip2718::Encodable2718, TxSigner, TxSignerSync};
+use alloy_primitives::{
+    eip191_hash_message, hex, keccak256, Address, ChainId, Signature, B256, U256,
+};
+use alloy_signer::{sign_transaction_with_chain_id, siwe::SiweMessage, Result, Signer, SignerSync};
 use async_trait::async_trait;
-use k256::ecdsa::{self, signature::hazmat::PrehashSigner, RecoveryId};
-use std::fmt;
+use k256::ecdsa::{
+    self,
+    signature::{
+        digest::{consts::U32, Digest, FixedOutput},
+        hazmat::PrehashSigner,
+    },
+    RecoveryId,
+};
+use std::{fmt, sync::Arc};
 
 mod error;
 pub use error::LocalSignerError;
 
+#[cfg(feature = "mnemonic")]
+mod hd;
+#[cfg(feature = "mnemonic")]
+pub use hd::HdWallet;
+
 #[cfg(feature = "mnemonic")]
 mod mnemonic;
 #[cfg(feature = "mnemonic")]
 pub use mnemonic::MnemonicBuilder;
 
 mod private_key;
+pub use private_key::find_duplicate_keys;
+
+mod profile;
+pub use profile::{ChainPrefs, TxForm, WalletProfile};
+
+#[cfg(feature = "testkit")]
+pub mod testkit;
 
 #[cfg(feature = "yubihsm")]
 mod yubi;
@@ -89,8 +110,21 @@ pub struct LocalSigner<C> {
     pub(crate) address: Address,
     /// The signer's chain ID (for EIP-155).
     pub(crate) chain_id: Option<ChainId>,
+    /// An optional human-readable label for the signer.
+    pub(crate) label: Option<String>,
+    /// An optional hook called with every hash before it is signed.
+    pub(crate) audit_hook: Option<AuditHook>,
+    /// Whether opaque hashes may be signed with [`sign_hash_sync`](SignerSync::sign_hash_sync).
+    pub(crate) allow_blind_signing: bool,
+    /// The maximum value of the transactions the signer signs, if any.
+    pub(crate) max_value: Option<U256>,
 }
 
+/// A hook called by a [`LocalSigner`] with every hash before it is signed.
+///
+/// See [`LocalSigner::with_audit_hook`].
+pub type AuditHook = Arc<dyn Fn(&B256) + Send + Sync>;
+
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl<C: PrehashSigner<(ecdsa::Signature, RecoveryId)> + Send + Sync> Signer for LocalSigner<C> {
@@ -99,6 +133,30 @@ impl<C: PrehashSigner<(ecdsa::Signature, RecoveryId)> + Send + Sync> Signer for
         self.sign_hash_sync(hash)
     }
 
+    #[inline]
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature> {
+        self.sign_message_sync(message)
+    }
+
+    #[cfg(feature = "eip712")]
+    #[inline]
+    async fn sign_typed_data<T: alloy_sol_types::SolStruct + Send + Sync>(
+        &self,
+        payload: &T,
+        domain: &alloy_sol_types::Eip712Domain,
+    ) -> Result<Signature> {
+        self.sign_typed_data_sync(payload, domain)
+    }
+
+    #[cfg(feature = "eip712")]
+    #[inline]
+    async fn sign_dynamic_typed_data(
+        &self,
+        payload: &alloy_dyn_abi::eip712::TypedData,
+    ) -> Result<Signature> {
+        self.sign_dynamic_typed_data_sync(payload)
+    }
+
     #[inline]
     fn address(&self) -> Address {
         self.address
@@ -118,8 +176,32 @@ impl<C: PrehashSigner<(ecdsa::Signature, RecoveryId)> + Send + Sync> Signer for
 impl<C: PrehashSigner<(ecdsa::Signature, RecoveryId)>> SignerSync for LocalSigner<C> {
     #[inline]
     fn sign_hash_sync(&self, hash: &B256) -> Result<Signature> {
-        let (recoverable_sig, recovery_id) = self.credential.sign_prehash(hash.as_ref())?;
-        Ok(Signature::from_signature_and_parity(recoverable_sig, recovery_id)?)
+        self.check_blind_signing()?;
+        self.sign_structured_hash(hash)
+    }
+
+    #[inline]
+    fn sign_message_sync(&self, message: &[u8]) -> Result<Signature> {
+        self.sign_structured_hash(&eip191_hash_message(message))
+    }
+
+    #[cfg(feature = "eip712")]
+    #[inline]
+    fn sign_typed_data_sync<T: alloy_sol_types::SolStruct>(
+        &self,
+        payload: &T,
+        domain: &alloy_sol_types::Eip712Domain,
+    ) -> Result<Signature> {
+        self.sign_structured_hash(&payload.eip712_signing_hash(domain))
+    }
+
+    #[cfg(feature = "eip712")]
+    #[inline]
+    fn sign_dynamic_typed_data_sync(
+        &self,
+        payload: &alloy_dyn_abi::eip712::TypedData,
+    ) -> Result<Signature> {
+        self.sign_structured_hash(&payload.eip712_signing_hash()?)
     }
 
     #[inline]
@@ -136,7 +218,25 @@ impl<C: PrehashSigner<(ecdsa::Signature, RecoveryId)>> LocalSigner<C> {
         address: Address,
         chain_id: Option<ChainId>,
     ) -> Self {
-        Self { credential, address, chain_id }
+        Self {
+            credential,
+            address,
+            chain_id,
+            label: None,
+            audit_hook: None,
+            allow_blind_signing: true,
+            max_value: None,
+        }
+    }
+
+    /// Signs a hash computed from structured input, such as a message, typed data or a
+    /// transaction, regardless of [`allow_blind_signing`](Self::allow_blind_signing).
+    pub(crate) fn sign_structured_hash(&self, hash: &B256) -> Result<Signature> {
+        if let Some(hook) = &self.audit_hook {
+            hook(hash);
+        }
+        let (recoverable_sig, recovery_id) = self.credential.sign_prehash(hash.as_ref())?;
+        Ok(Signature::from_signature_and_parity(recoverable_sig, recovery_id)?)
     }
 
     /// Returns this signer's credential.
@@ -157,11 +257,320 @@ impl<C: PrehashSigner<(ecdsa::Signature, RecoveryId)>> LocalSigner<C> {
         self.address
     }
 
+    /// Compares this signer's address with `other` in constant time.
+    ///
+    /// Addresses are public, so this is only defense in depth for code that must not branch on
+    /// secret-dependent comparisons, such as checking a recovered signer against an authorized
+    /// one.
+    #[cfg(feature = "ct")]
+    pub fn ct_eq_address(&self, other: &Address) -> bool {
+        use subtle::ConstantTimeEq;
+        self.address.as_slice().ct_eq(other.as_slice()).into()
+    }
+
     /// Returns this signer's chain ID.
     #[inline]
     pub const fn chain_id(&self) -> Option<ChainId> {
         self.chain_id
     }
+
+    /// Returns this signer's label, if any.
+    #[inline]
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Sets a human-readable label for this signer, e.g. for display in wallet managers.
+    ///
+    /// The label is metadata only: it is not taken into account when comparing signers.
+    #[inline]
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets a hook that is called with the hash before every [`sign_hash_sync`] call, e.g. to
+    /// keep a central audit log of everything signed by this signer.
+    ///
+    /// All signing methods, such as message, typed data, transaction and
+    /// [digest](Self::sign_digest_sync) signing, go through the same path as [`sign_hash_sync`],
+    /// so the hook sees every signature produced by the signer. The hook only ever receives the
+    /// hash being signed, never any key material.
+    ///
+    /// [`sign_hash_sync`]: SignerSync::sign_hash_sync
+    #[inline]
+    pub fn with_audit_hook(mut self, hook: AuditHook) -> Self {
+        self.audit_hook = Some(hook);
+        self
+    }
+
+    /// Sets whether opaque hashes may be signed, which is allowed by default.
+    ///
+    /// When `false`, [`sign_hash_sync`](SignerSync::sign_hash_sync), the methods signing a
+    /// caller-provided hash, such as [`sign_safe_tx_hash_sync`](Self::sign_safe_tx_hash_sync),
+    /// and [`sign_digest_sync`](Self::sign_digest_sync), which signs a digest of arbitrary bytes,
+    /// return [`LocalSignerError::BlindSigningDisabled`], wrapped in an
+    /// [`alloy_signer::Error::Other`]. Only methods with structured inputs, whose hash the signer
+    /// computes itself, are permitted: message, typed data and transaction signing. This mirrors
+    /// the blind signing setting of hardware wallets, and guards against accidentally signing a
+    /// hash whose preimage is unknown.
+    #[inline]
+    pub const fn allow_blind_signing(mut self, allow: bool) -> Self {
+        self.allow_blind_signing = allow;
+        self
+    }
+
+    /// Returns an error if [blind signing](Self::allow_blind_signing) is disabled, or a
+    /// [maximum value](Self::with_max_value) is set.
+    pub(crate) fn check_blind_signing(&self) -> Result<()> {
+        if !self.allow_blind_signing || self.max_value.is_some() {
+            return Err(alloy_signer::Error::other(LocalSignerError::BlindSigningDisabled));
+        }
+        Ok(())
+    }
+
+    /// Sets the maximum value of the transactions the signer signs, e.g. to require a separate
+    /// authorization for larger transfers.
+    ///
+    /// Signing a transaction whose [value](alloy_consensus::Transaction::value) exceeds `max`
+    /// returns [`LocalSignerError::ValueExceedsLimit`], wrapped in an
+    /// [`alloy_signer::Error::Other`] for the [`TxSigner`] and [`TxSignerSync`] methods.
+    ///
+    /// An opaque hash or digest may be the signature hash of any transaction, so setting a
+    /// maximum value also disables [blind signing](Self::allow_blind_signing), regardless of
+    /// that setting: [`sign_hash_sync`](SignerSync::sign_hash_sync),
+    /// [`sign_digest_sync`](Self::sign_digest_sync) and the other methods signing
+    /// caller-provided hashes or prefixes return [`LocalSignerError::BlindSigningDisabled`].
+    #[inline]
+    pub const fn with_max_value(mut self, max: U256) -> Self {
+        self.max_value = Some(max);
+        self
+    }
+
+    /// Returns an error if the value exceeds the [maximum value](Self::with_max_value).
+    pub(crate) fn check_value(&self, value: U256) -> Result<(), LocalSignerError> {
+        match self.max_value {
+            Some(max) if value > max => Err(LocalSignerError::ValueExceedsLimit { value, max }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the non-secret metadata of this signer.
+    ///
+    /// Unlike the signer itself, the returned [`LocalSignerInfo`] can be freely logged or
+    /// serialized, as it never contains key material.
+    #[inline]
+    pub const fn info(&self) -> LocalSignerInfo {
+        LocalSignerInfo { address: self.address, chain_id: self.chain_id }
+    }
+
+    /// Signs the transaction and returns its `0x`-prefixed, [EIP-2718] encoded raw bytes, ready
+    /// to be submitted with `eth_sendRawTransaction`.
+    ///
+    /// [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+    pub fn sign_transaction_raw_sync<T>(&self, mut tx: T) -> Result<String>
+    where
+        T: SignableTransaction<Signature>,
+        Signed<T>: Into<TxEnvelope>,
+    {
+        let signature = self.sign_transaction_sync(&mut tx)?;
+        let envelope: TxEnvelope = tx.into_signed(signature).into();
+        Ok(hex::encode_prefixed(envelope.encoded_2718()))
+    }
+
+    /// Bumps the fees of the transaction by at least `min_bump_percent` and signs it, e.g. to
+    /// replace a stuck transaction with the same nonce.
+    ///
+    /// Most nodes only accept a replacement if its fees are at least 10% higher than those of the
+    /// pending transaction. See [`SignableTransaction::bump_fees`] for the bumped fields.
+    pub fn sign_replacement_sync<T: SignableTransaction<Signature>>(
+        &self,
+        mut tx: T,
+        min_bump_percent: u16,
+    ) -> Result<Signed<T>> {
+        tx.bump_fees(min_bump_percent);
+        let signature = self.sign_transaction_sync(&mut tx)?;
+        Ok(tx.into_signed(signature))
+    }
+
+    /// Signs a [Safe] transaction hash, producing an EIP-712 signature that Safe verifies with
+    /// `ecrecover(safeTxHash, v, r, s)`.
+    ///
+    /// The Safe transaction hash is already an [EIP-712] digest, so it is signed as-is. When packed
+    /// with [`Signature::as_bytes`], `v` is encoded as `27` or `28`, as Safe expects.
+    ///
+    /// See [`sign_safe_tx_hash_eth_sign_sync`](Self::sign_safe_tx_hash_eth_sign_sync) for the
+    /// `eth_sign` flavour.
+    ///
+    /// [Safe]: https://github.com/safe-global/safe-smart-account
+    /// [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+    pub fn sign_safe_tx_hash_sync(&self, safe_tx_hash: &B256) -> Result<Signature> {
+        Ok(self.sign_hash_sync(safe_tx_hash)?.with_parity_bool())
+    }
+
+    /// Signs a [Safe] transaction hash using the `eth_sign` flavour, returning the 65-byte packed
+    /// `r || s || v` signature as submitted to Safe.
+    ///
+    /// The hash is signed as an [EIP-191] personal message. To signal this to the contract, Safe
+    /// requires `v` to be offset by 4, i.e. `31` or `32`, which cannot be represented by
+    /// [`Signature`] and is why the packed bytes are returned instead.
+    ///
+    /// [Safe]: https://github.com/safe-global/safe-smart-account
+    /// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+    pub fn sign_safe_tx_hash_eth_sign_sync(&self, safe_tx_hash: &B256) -> Result<[u8; 65]> {
+        let mut bytes = self.sign_hash_sync(&eip191_hash_message(safe_tx_hash))?.as_bytes();
+        bytes[64] += 4;
+        Ok(bytes)
+    }
+
+    /// Signs an [EIP-4361] Sign-In with Ethereum message.
+    ///
+    /// The [canonical text](SiweMessage::to_message_string) of the message is signed as an
+    /// [EIP-191] personal message. The signature can be checked with [`SiweMessage::verify`].
+    ///
+    /// [EIP-4361]: https://eips.ethereum.org/EIPS/eip-4361
+    /// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+    pub fn sign_siwe_sync(&self, message: &SiweMessage) -> Result<Signature> {
+        self.sign_message_sync(message.to_message_string().as_bytes())
+    }
+
+    /// Signs `data` as an [EIP-191] version `0x00` message, i.e. data with an intended
+    /// validator.
+    ///
+    /// The signed hash is `keccak256(0x19 || 0x00 || validator || data)`, where `validator` is the
+    /// address of the contract that verifies the signature.
+    ///
+    /// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+    pub fn sign_eip191_v0_sync(&self, validator: Address, data: &[u8]) -> Result<Signature> {
+        let preimage = [&[0x19, 0x00], validator.as_slice(), data].concat();
+        self.sign_structured_hash(&keccak256(preimage))
+    }
+
+    /// Signs an off-chain attestation to an [EIP-4844] blob, identified by its versioned hash.
+    ///
+    /// The signed digest is `keccak256(0x19 || "Blob Commitment:\n" || versioned_hash)`, i.e.
+    /// the byte `0x19` followed by the 17 ASCII bytes of `Blob Commitment:\n` and the 32 bytes
+    /// of the versioned hash. Like other [EIP-191] preimages, it starts with `0x19` and is
+    /// followed by a byte that is neither an RLP list prefix, so it can never be the signing
+    /// preimage of a transaction, nor a version byte assigned by EIP-191 (`0x00`, `0x01` or
+    /// `0x45`), so it does not collide with messages, typed data or validator data.
+    ///
+    /// [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
+    /// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+    pub fn sign_blob_commitment_sync(&self, versioned_hash: &B256) -> Result<Signature> {
+        let preimage = [b"\x19Blob Commitment:\n", versioned_hash.as_slice()].concat();
+        self.sign_structured_hash(&keccak256(preimage))
+    }
+
+    /// Signs a message like [`sign_message_sync`](SignerSync::sign_message_sync), but with a
+    /// custom prefix instead of the [EIP-191] `"\x19Ethereum Signed Message:\n"` one.
+    ///
+    /// The signed hash is `keccak256(prefix || len || message)`, where `len` is the length of the
+    /// message as an ASCII decimal number. This is only meant for applications that verify
+    /// messages with their own prefix:
+    /// - the signature cannot be verified by standard tooling, such as `ecrecover` based
+    ///   `personal_sign` verifiers or [`Signature::recover_address_from_msg`];
+    /// - a prefix not starting with `0x19` gives no domain separation from other signed data, so
+    ///   the preimage may, for example, be a valid transaction.
+    ///
+    /// As the signed preimage is then chosen by the caller, this is not available when
+    /// [blind signing](Self::allow_blind_signing) is disabled.
+    ///
+    /// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+    pub fn sign_message_custom_prefix_sync(
+        &self,
+        prefix: &str,
+        message: &[u8],
+    ) -> Result<Signature> {
+        let len = message.len().to_string();
+        let preimage = [prefix.as_bytes(), len.as_bytes(), message].concat();
+        self.sign_hash_sync(&keccak256(preimage))
+    }
+
+    /// Signs the hash like [`sign_hash_sync`](SignerSync::sign_hash_sync), returning the
+    /// signature together with the signed hash, the recovery ID and the signer address.
+    pub fn sign_hash_detailed_sync(&self, hash: &B256) -> Result<SignedHash> {
+        let signature = self.sign_hash_sync(hash)?;
+        Ok(SignedHash {
+            hash: *hash,
+            signature,
+            recovery_id: signature.v().y_parity_byte(),
+            signer: self.address,
+        })
+    }
+
+    /// Signs the hash like [`sign_hash_sync`](SignerSync::sign_hash_sync), attaching `ctx` to any
+    /// error as [`LocalSignerError::SigningError`].
+    ///
+    /// The context helps locating failures in services managing many signers, e.g.
+    /// `"sign_withdrawal (hot-wallet-2)"`. It is included verbatim in the error message, so it
+    /// must never contain key material; the signer itself adds nothing to it.
+    pub fn sign_hash_ctx_sync(
+        &self,
+        hash: &B256,
+        ctx: &str,
+    ) -> Result<Signature, LocalSignerError> {
+        self.sign_hash_sync(hash)
+            .map_err(|source| LocalSignerError::SigningError { context: ctx.to_string(), source })
+    }
+
+    /// Signs the message prehashed with the digest `D` instead of Keccak-256.
+    ///
+    /// This allows reusing the same secp256k1 key on chains that use a different message hash,
+    /// e.g. SHA-256. The resulting signature recovers against `D::digest(message)`. Ethereum
+    /// signing methods such as [`sign_message_sync`](SignerSync::sign_message_sync) are unaffected
+    /// and keep using Keccak-256.
+    ///
+    /// `D` must produce 32 byte digests, which are passed to the
+    /// [audit hook](Self::with_audit_hook) like any other signed hash.
+    ///
+    /// Returns [`LocalSignerError::BlindSigningDisabled`] if
+    /// [blind signing](Self::allow_blind_signing) is disabled, as the message may be a
+    /// transaction payload.
+    ///
+    /// # ⚠️ Warning
+    ///
+    /// Reusing a key across chains with different hash functions means a signature produced for
+    /// one chain may be a valid signature over unrelated data on another, if the two digests ever
+    /// coincide or a protocol signs attacker-controlled prehashes. Only use this with digests and
+    /// message formats that provide their own domain separation.
+    pub fn sign_digest_sync<D>(&self, message: &[u8]) -> Result<Signature>
+    where
+        D: Digest + FixedOutput<OutputSize = U32>,
+    {
+        self.check_blind_signing()?;
+        self.sign_structured_hash(&B256::from_slice(&D::digest(message)))
+    }
+}
+
+/// The public profile of a [`LocalSigner`], excluding its credential.
+///
+/// See [`LocalSigner::info`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct LocalSignerInfo {
+    /// The signer's address.
+    pub address: Address,
+    /// The signer's chain ID (for EIP-155).
+    pub chain_id: Option<ChainId>,
+}
+
+/// A signature produced by a [`LocalSigner`], with the metadata of the signing operation.
+///
+/// See [`LocalSigner::sign_hash_detailed_sync`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct SignedHash {
+    /// The hash that was signed.
+    pub hash: B256,
+    /// The signature over the hash.
+    pub signature: Signature,
+    /// The recovery ID of the signature, `0` or `1`.
+    pub recovery_id: u8,
+    /// The address of the signer.
+    pub signer: Address,
 }
 
 // do not log the signer
@@ -170,6 +579,10 @@ impl<C: PrehashSigner<(ecdsa::Signature, RecoveryId)>> fmt::Debug for LocalSigne
         f.debug_struct("LocalSigner")
             .field("address", &self.address)
             .field("chain_id", &self.chain_id)
+            .field("label", &self.label)
+            .field("audit_hook", &self.audit_hook.is_some())
+            .field("allow_blind_signing", &self.allow_blind_signing)
+            .field("max_value", &self.max_value)
             .finish()
     }
 }
@@ -189,7 +602,8 @@ where
         &self,
         tx: &mut dyn SignableTransaction<Signature>,
     ) -> alloy_signer::Result<Signature> {
-        sign_transaction_with_chain_id!(self, tx, self.sign_hash_sync(&tx.signature_hash()))
+        self.check_value(tx.value()).map_err(alloy_signer::Error::other)?;
+        sign_transaction_with_chain_id!(self, tx, self.sign_structured_hash(&tx.signature_hash()))
     }
 }
 
@@ -206,15 +620,17 @@ where
         &self,
         tx: &mut dyn SignableTransaction<Signature>,
     ) -> alloy_signer::Result<Signature> {
-        sign_transaction_with_chain_id!(self, tx, self.sign_hash_sync(&tx.signature_hash()))
+        self.check_value(tx.value()).map_err(alloy_signer::Error::other)?;
+        sign_transaction_with_chain_id!(self, tx, self.sign_structured_hash(&tx.signature_hash()))
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use alloy_consensus::TxLegacy;
-    use alloy_primitives::{address, U256};
+    use alloy_consensus::{TxEip1559, TxLegacy};
+    use alloy_network::eip2718::Decodable2718;
+    use alloy_primitives::{address, b256, utils::EIP191_PREFIX};
 
     #[tokio::test]
     async fn signs_tx() {
@@ -290,4 +706,397 @@ mod test {
         let expected_error = alloy_signer::Error::TransactionChainIdMismatch { signer: 1, tx: 2 };
         assert_eq!(error.to_string(), expected_error.to_string());
     }
+
+    #[test]
+    fn signs_tx_raw() {
+        let signer: PrivateKeySigner =
+            "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318".parse().unwrap();
+        let signer = signer.with_chain_id(Some(1));
+
+        let tx = TxEip1559 {
+            chain_id: 1,
+            nonce: 0,
+            gas_limit: 21_000,
+            max_fee_per_gas: 20_000_000_000,
+            max_priority_fee_per_gas: 1_000_000_000,
+            to: address!("F0109fC8DF283027b6285cc889F5aA624EaC1F55").into(),
+            value: U256::from(1_000_000_000),
+            ..Default::default()
+        };
+        let raw = signer.sign_transaction_raw_sync(tx.clone()).unwrap();
+        assert!(raw.starts_with("0x02"));
+
+        let decoded = TxEnvelope::decode_2718(&mut hex::decode(&raw).unwrap().as_slice()).unwrap();
+        let signed = decoded.as_eip1559().unwrap();
+        assert_eq!(*signed.tx(), tx);
+        let recovered =
+            signed.signature().recover_address_from_prehash(&signed.signature_hash()).unwrap();
+        assert_eq!(recovered, signer.address());
+    }
+
+    #[test]
+    fn signs_safe_tx_hash() {
+        let signer = PrivateKeySigner::random();
+        let safe_tx_hash = alloy_primitives::keccak256("safe tx");
+
+        // Safe: `v` of 27 or 28 is verified with `ecrecover(dataHash, v, r, s)`
+        let sig = signer.sign_safe_tx_hash_sync(&safe_tx_hash).unwrap();
+        let v = sig.as_bytes()[64];
+        assert!(v == 27 || v == 28);
+        assert_eq!(sig.recover_address_from_prehash(&safe_tx_hash).unwrap(), signer.address());
+
+        // Safe: `v` greater than 30 is verified with
+        // `ecrecover(keccak256("\x19Ethereum Signed Message:\n32" || dataHash), v - 4, r, s)`
+        let bytes = signer.sign_safe_tx_hash_eth_sign_sync(&safe_tx_hash).unwrap();
+        let v = bytes[64];
+        assert!(v == 31 || v == 32);
+        let sig = Signature::from_bytes_and_parity(&bytes[..64], (v - 4) as u64).unwrap();
+        assert_eq!(sig.recover_address_from_msg(safe_tx_hash).unwrap(), signer.address());
+    }
+
+    #[test]
+    fn signs_siwe() {
+        let signer = PrivateKeySigner::random();
+        let message = SiweMessage::new(
+            "example.com",
+            signer.address(),
+            "https://example.com/login",
+            1,
+            "32891756",
+            "2021-09-30T16:25:24Z",
+        )
+        .with_statement("Sign in to example.com");
+
+        let sig = signer.sign_siwe_sync(&message).unwrap();
+        message.verify(&sig).unwrap();
+
+        let other = PrivateKeySigner::random();
+        let sig = other.sign_siwe_sync(&message).unwrap();
+        assert!(matches!(
+            message.verify(&sig),
+            Err(alloy_signer::Error::AddressMismatch { expected, recovered })
+                if expected == signer.address() && recovered == other.address()
+        ));
+    }
+
+    #[test]
+    fn signs_replacement() {
+        let signer = PrivateKeySigner::random();
+        let tx = TxEip1559 {
+            chain_id: 1,
+            nonce: 7,
+            gas_limit: 21_000,
+            max_fee_per_gas: 30_000_000_000,
+            max_priority_fee_per_gas: 1_500_000_000,
+            to: address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045").into(),
+            ..Default::default()
+        };
+
+        let signed = signer.sign_replacement_sync(tx.clone(), 10).unwrap();
+        let recovered = signed.signature().recover_address_from_prehash(&signed.signature_hash());
+        assert_eq!(recovered.unwrap(), signer.address());
+        assert_eq!(signed.tx().nonce, tx.nonce);
+        assert!(signed.tx().max_fee_per_gas * 100 >= tx.max_fee_per_gas * 110);
+        assert!(signed.tx().max_priority_fee_per_gas * 100 >= tx.max_priority_fee_per_gas * 110);
+
+        let legacy = TxLegacy { gas_price: 20_000_000_000, ..Default::default() };
+        let replacement = signer.sign_replacement_sync(legacy.clone(), 10).unwrap();
+        assert!(replacement.tx().gas_price * 100 >= legacy.gas_price * 110);
+    }
+
+    #[test]
+    fn audit_hook() {
+        use std::sync::Mutex;
+
+        let hashes = Arc::new(Mutex::new(Vec::new()));
+        let log = hashes.clone();
+        let signer = PrivateKeySigner::random()
+            .with_audit_hook(Arc::new(move |hash| log.lock().unwrap().push(*hash)));
+
+        let message = b"hello world";
+        signer.sign_message_sync(message).unwrap();
+        assert_eq!(*hashes.lock().unwrap(), [eip191_hash_message(message)]);
+
+        let hash = B256::repeat_byte(0x11);
+        signer.sign_hash_sync(&hash).unwrap();
+        assert_eq!(*hashes.lock().unwrap(), [eip191_hash_message(message), hash]);
+    }
+
+    #[test]
+    fn signs_digest() {
+        use k256::sha2::{Digest, Sha256};
+
+        let signer = PrivateKeySigner::random();
+        let message = b"hello world";
+
+        let sig = signer.sign_digest_sync::<Sha256>(message).unwrap();
+        let prehash = B256::from_slice(&Sha256::digest(message));
+        assert_eq!(sig.recover_from_prehash(&prehash).unwrap(), *signer.verifying_key());
+        assert_eq!(sig.recover_address_from_prehash(&prehash).unwrap(), signer.address());
+
+        // the Ethereum path is unaffected
+        assert_ne!(sig, signer.sign_message_sync(message).unwrap());
+
+        // the audit hook sees the digest
+        let count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let hook_count = count.clone();
+        let signer = signer.with_audit_hook(Arc::new(move |hash| {
+            assert_eq!(*hash, prehash);
+            hook_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        }));
+        assert_eq!(signer.sign_digest_sync::<Sha256>(message).unwrap(), sig);
+        assert_eq!(count.load(std::sync::atomic::Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn signs_eip191_v0() {
+        let signer = PrivateKeySigner::random();
+        let validator = address!("6069a6c32cf691f5982febae4faf8a6f3ab2f0f6");
+        let data = b"hello world";
+
+        let sig = signer.sign_eip191_v0_sync(validator, data).unwrap();
+        let mut preimage = vec![0x19, 0x00];
+        preimage.extend_from_slice(validator.as_slice());
+        preimage.extend_from_slice(data);
+        let hash = keccak256(&preimage);
+        assert_eq!(sig.recover_address_from_prehash(&hash).unwrap(), signer.address());
+
+        // the validator is part of the signed data
+        let other = signer.sign_eip191_v0_sync(Address::ZERO, data).unwrap();
+        assert_ne!(other.recover_address_from_prehash(&hash).ok(), Some(signer.address()));
+    }
+
+    #[cfg(feature = "ct")]
+    #[test]
+    fn ct_eq_address() {
+        let signer = PrivateKeySigner::random();
+        assert!(signer.ct_eq_address(&signer.address()));
+        assert!(!signer.ct_eq_address(&Address::ZERO));
+
+        let mut other = signer.address();
+        other.0[19] ^= 1;
+        assert!(!signer.ct_eq_address(&other));
+    }
+
+    #[test]
+    fn signs_hash_detailed() {
+        let signer = PrivateKeySigner::random();
+        let hash = B256::repeat_byte(0x42);
+
+        let signed = signer.sign_hash_detailed_sync(&hash).unwrap();
+        assert_eq!(signed.hash, hash);
+        assert_eq!(signed.signer, signer.address());
+        assert!(signed.recovery_id <= 1);
+        assert_eq!(signed.recovery_id, signed.signature.recid().to_byte());
+        assert_eq!(signed.signature.recover_address_from_prehash(&hash).unwrap(), signed.signer);
+        assert_eq!(signed.signature, signer.sign_hash_sync(&hash).unwrap());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_info() {
+        let signer = PrivateKeySigner::random().with_chain_id(Some(1));
+        let info = signer.info();
+        assert_eq!(info, LocalSignerInfo { address: signer.address(), chain_id: Some(1) });
+
+        let json = serde_json::to_string(&info).unwrap();
+        assert_eq!(json, format!(r#"{{"address":"{:#x}","chainId":1}}"#, signer.address()));
+        assert!(!json.contains(&hex::encode(signer.to_bytes())));
+        assert_eq!(serde_json::from_str::<LocalSignerInfo>(&json).unwrap(), info);
+    }
+
+    #[test]
+    fn sign_hash_ctx_error() {
+        struct FailingCredential;
+
+        impl PrehashSigner<(ecdsa::Signature, RecoveryId)> for FailingCredential {
+            fn sign_prehash(
+                &self,
+                _prehash: &[u8],
+            ) -> std::result::Result<(ecdsa::Signature, RecoveryId), ecdsa::Error> {
+                Err(ecdsa::Error::new())
+            }
+        }
+
+        let signer = LocalSigner::new_with_credential(FailingCredential, Address::ZERO, None);
+        let err =
+            signer.sign_hash_ctx_sync(&B256::ZERO, "sign_withdrawal (hot-wallet)").unwrap_err();
+        assert_eq!(err.context(), Some("sign_withdrawal (hot-wallet)"));
+        assert!(matches!(
+            err,
+            LocalSignerError::SigningError { source: alloy_signer::Error::Ecdsa(_), .. }
+        ));
+        assert!(err.to_string().starts_with("sign_withdrawal (hot-wallet): "));
+
+        let signer = PrivateKeySigner::random();
+        let hash = B256::repeat_byte(1);
+        assert_eq!(
+            signer.sign_hash_ctx_sync(&hash, "ok").unwrap(),
+            signer.sign_hash_sync(&hash).unwrap()
+        );
+        assert_eq!(LocalSignerError::VanityNotFound { attempts: 1 }.context(), None);
+    }
+
+    #[tokio::test]
+    async fn blind_signing_disabled() {
+        let signer = PrivateKeySigner::random().allow_blind_signing(false);
+        let hash = B256::repeat_byte(1);
+
+        let is_disabled = |err: alloy_signer::Error| {
+            matches!(
+                err,
+                alloy_signer::Error::Other(err)
+                    if matches!(err.downcast_ref(), Some(LocalSignerError::BlindSigningDisabled))
+            )
+        };
+        assert!(is_disabled(signer.sign_hash_sync(&hash).unwrap_err()));
+        assert!(is_disabled(signer.sign_hash(&hash).await.unwrap_err()));
+        assert!(is_disabled(signer.sign_safe_tx_hash_sync(&hash).unwrap_err()));
+        // with Keccak-256, any preimage could be signed, e.g. a transaction signing payload
+        let tx = TxLegacy { chain_id: Some(1), ..Default::default() };
+        assert!(is_disabled(
+            signer.sign_digest_sync::<sha3::Keccak256>(&tx.encoded_for_signing()).unwrap_err()
+        ));
+        #[cfg(feature = "dangerous-testing")]
+        assert!(is_disabled(
+            signer.sign_hash_with_k_sync(&hash, &B256::with_last_byte(1)).unwrap_err()
+        ));
+
+        let message = b"hello";
+        let signature = signer.sign_message_sync(message).unwrap();
+        assert_eq!(signature.recover_address_from_msg(message).unwrap(), signer.address());
+        assert_eq!(signer.sign_message(message).await.unwrap(), signature);
+
+        let mut tx = TxLegacy { chain_id: Some(1), ..Default::default() };
+        let signature = signer.sign_transaction_sync(&mut tx).unwrap();
+        assert_eq!(
+            signature.recover_address_from_prehash(&tx.signature_hash()).unwrap(),
+            signer.address()
+        );
+
+        #[cfg(feature = "eip712")]
+        {
+            alloy_sol_types::sol! {
+                struct Mail {
+                    string contents;
+                }
+            }
+            let mail = Mail { contents: "hello".into() };
+            let domain = alloy_sol_types::eip712_domain! { name: "Test", version: "1", };
+            let signature = signer.sign_typed_data_sync(&mail, &domain).unwrap();
+            assert_eq!(
+                signature
+                    .recover_address_from_prehash(&alloy_sol_types::SolStruct::eip712_signing_hash(
+                        &mail, &domain
+                    ))
+                    .unwrap(),
+                signer.address()
+            );
+        }
+    }
+
+    #[test]
+    fn blind_signing_allowed() {
+        let signer = PrivateKeySigner::random();
+        let hash = B256::repeat_byte(1);
+        let signature = signer.sign_hash_sync(&hash).unwrap();
+        assert_eq!(signature.recover_address_from_prehash(&hash).unwrap(), signer.address());
+
+        let signer = signer.allow_blind_signing(false).allow_blind_signing(true);
+        assert_eq!(signer.sign_hash_sync(&hash).unwrap(), signature);
+    }
+
+    #[test]
+    fn signs_blob_commitment() {
+        let signer = PrivateKeySigner::random();
+        let versioned_hash =
+            b256!("01b0a4cdd5f55589f5c5b4d46c76704bb6ce95c0a8c09f77f197a57808dded28");
+
+        let signature = signer.sign_blob_commitment_sync(&versioned_hash).unwrap();
+        let mut preimage = vec![0x19];
+        preimage.extend_from_slice(b"Blob Commitment:\n");
+        preimage.extend_from_slice(versioned_hash.as_slice());
+        assert_eq!(preimage.len(), 1 + 17 + 32);
+        assert_eq!(
+            signature.recover_address_from_prehash(&keccak256(&preimage)).unwrap(),
+            signer.address()
+        );
+
+        // not a plain signature over the versioned hash
+        assert_ne!(signature, signer.sign_hash_sync(&versioned_hash).unwrap());
+        // allowed even when blind signing is disabled, as the signer computes the digest
+        let signer = signer.allow_blind_signing(false);
+        assert_eq!(signer.sign_blob_commitment_sync(&versioned_hash).unwrap(), signature);
+    }
+
+    #[test]
+    fn signs_message_custom_prefix() {
+        let signer = PrivateKeySigner::random();
+        let message = b"hello world";
+
+        let signature = signer
+            .sign_message_custom_prefix_sync("\x19Custom Signed Message:\n", message)
+            .unwrap();
+        let hash = keccak256(b"\x19Custom Signed Message:\n11hello world");
+        assert_eq!(signature.recover_address_from_prehash(&hash).unwrap(), signer.address());
+
+        // differs from the standard EIP-191 path
+        let standard = signer.sign_message_sync(message).unwrap();
+        assert_ne!(signature, standard);
+        assert_ne!(signature.recover_address_from_msg(message).ok(), Some(signer.address()));
+        // the EIP-191 prefix gives the standard signature
+        assert_eq!(
+            signer.sign_message_custom_prefix_sync(EIP191_PREFIX, message).unwrap(),
+            standard
+        );
+
+        let signer = signer.allow_blind_signing(false);
+        assert!(signer.sign_message_custom_prefix_sync("\x19Custom:\n", message).is_err());
+    }
+
+    #[tokio::test]
+    async fn max_value() {
+        let max = U256::from(1_000_000_000_000_000_000u128);
+        let signer = PrivateKeySigner::random().with_max_value(max);
+
+        let mut tx = TxEip1559 { chain_id: 1, value: max, ..Default::default() };
+        let signature = signer.sign_transaction_sync(&mut tx).unwrap();
+        assert_eq!(
+            signature.recover_address_from_prehash(&tx.signature_hash()).unwrap(),
+            signer.address()
+        );
+
+        let exceeds_limit = |err: alloy_signer::Error| {
+            matches!(
+                err,
+                alloy_signer::Error::Other(err) if matches!(
+                    err.downcast_ref(),
+                    Some(LocalSignerError::ValueExceedsLimit { value, max: limit })
+                        if *value == max + U256::from(1) && *limit == max
+                )
+            )
+        };
+        let mut tx = TxEip1559 { value: max + U256::from(1), ..tx };
+        assert!(exceeds_limit(signer.sign_transaction_sync(&mut tx).unwrap_err()));
+        assert!(exceeds_limit(signer.sign_transaction(&mut tx).await.unwrap_err()));
+
+        // the limit cannot be bypassed by signing the signature hash or payload directly, even
+        // if blind signing is explicitly allowed
+        let signer = signer.allow_blind_signing(true);
+        let is_disabled = |err: alloy_signer::Error| {
+            matches!(
+                err,
+                alloy_signer::Error::Other(err)
+                    if matches!(err.downcast_ref(), Some(LocalSignerError::BlindSigningDisabled))
+            )
+        };
+        assert!(is_disabled(signer.sign_hash_sync(&tx.signature_hash()).unwrap_err()));
+        assert!(is_disabled(signer.sign_hash(&tx.signature_hash()).await.unwrap_err()));
+        assert!(is_disabled(
+            signer.sign_digest_sync::<sha3::Keccak256>(&tx.encoded_for_signing()).unwrap_err()
+        ));
+        assert!(is_disabled(
+            signer.sign_message_custom_prefix_sync("", &tx.encoded_for_signing()).unwrap_err()
+        ));
+    }
 }
@@ -4,6 +4,7 @@
 //! [BIP-39]: https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki
 
 use crate::{LocalSigner, LocalSignerError, PrivateKeySigner};
+use alloy_primitives::Address;
 use alloy_signer::utils::secret_key_to_address;
 use coins_bip32::path::DerivationPath;
 use coins_bip39::{English, Mnemonic, Wordlist};
@@ -171,7 +172,54 @@ impl<W: Wordlist> MnemonicBuilder<W> {
         let key: &coins_bip32::prelude::SigningKey = derived_priv_key.as_ref();
         let credential = SigningKey::from_bytes(&key.to_bytes())?;
         let address = secret_key_to_address(&credential);
-        Ok(LocalSigner::<SigningKey> { credential, address, chain_id: None })
+        Ok(LocalSigner::new_with_credential(credential, address, None))
+    }
+}
+
+impl PrivateKeySigner {
+    /// Discovers the used accounts of an [English] mnemonic phrase, following the [BIP-44]
+    /// account discovery algorithm.
+    ///
+    /// Accounts are derived sequentially at `{base_path}/{index}`, starting from index `0`, e.g.
+    /// with the default Ethereum base path `m/44'/60'/0'/0`. Each address is checked with
+    /// `is_used`, and the scan stops after `gap_limit` consecutive unused addresses, e.g. 20 as
+    /// recommended by BIP-44. Returns the used accounts, in derivation order.
+    ///
+    /// At most `max_accounts` addresses are derived, so that a predicate that keeps reporting
+    /// used addresses, e.g. because of a broken RPC endpoint, cannot make the scan run forever.
+    /// Returns [`LocalSignerError::AccountScanLimitReached`] if the limit is reached before the
+    /// gap limit.
+    ///
+    /// [BIP-44]: https://github.com/bitcoin/bips/blob/master/bip-0044.mediawiki#account-discovery
+    pub fn scan_accounts(
+        mnemonic: &str,
+        base_path: &str,
+        gap_limit: usize,
+        max_accounts: u32,
+        is_used: impl Fn(Address) -> bool,
+    ) -> Result<Vec<Self>, LocalSignerError> {
+        let mnemonic = Mnemonic::<English>::new_from_phrase(mnemonic)?;
+        let base_path = base_path.trim_end_matches('/');
+        let mut builder = MnemonicBuilder::<English>::default();
+
+        let mut accounts = Vec::new();
+        let mut gap = 0;
+        let mut index = 0u32;
+        while gap < gap_limit {
+            if index == max_accounts {
+                return Err(LocalSignerError::AccountScanLimitReached { max_accounts });
+            }
+            builder = builder.derivation_path(format!("{base_path}/{index}"))?;
+            let signer = builder.mnemonic_to_signer(&mnemonic)?;
+            if is_used(signer.address) {
+                accounts.push(signer);
+                gap = 0;
+            } else {
+                gap += 1;
+            }
+            index += 1;
+        }
+        Ok(accounts)
     }
 }
 
@@ -179,6 +227,7 @@ impl<W: Wordlist> MnemonicBuilder<W> {
 mod tests {
     use super::*;
     use coins_bip39::English;
+    use std::cell::Cell;
     use tempfile::tempdir;
 
     const TEST_DERIVATION_PATH: &str = "m/44'/60'/0'/2/1";
@@ -254,4 +303,50 @@ mod tests {
 
         dir.close().unwrap();
     }
+
+    #[test]
+    fn scan_accounts() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let derive = |index| {
+            MnemonicBuilder::<English>::default()
+                .phrase(phrase)
+                .index(index)
+                .unwrap()
+                .build()
+                .unwrap()
+        };
+        let (first, second) = (derive(0).address, derive(1).address);
+
+        let calls = Cell::new(0);
+        let accounts =
+            PrivateKeySigner::scan_accounts(phrase, "m/44'/60'/0'/0", 1, 100, |address| {
+                calls.set(calls.get() + 1);
+                address == first || address == second
+            })
+            .unwrap();
+        assert_eq!(accounts.iter().map(|a| a.address).collect::<Vec<_>>(), [first, second]);
+        assert_eq!(calls.get(), 3);
+
+        let accounts =
+            PrivateKeySigner::scan_accounts(phrase, "m/44'/60'/0'/0/", 3, 100, |address| {
+                address == second
+            })
+            .unwrap();
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].address, second);
+
+        assert!(PrivateKeySigner::scan_accounts(phrase, "m/44'/60'/0'/0", 0, 100, |_| true)
+            .unwrap()
+            .is_empty());
+
+        // a predicate reporting every address as used stops at the limit
+        let calls = Cell::new(0);
+        let err = PrivateKeySigner::scan_accounts(phrase, "m/44'/60'/0'/0", 20, 5, |_| {
+            calls.set(calls.get() + 1);
+            true
+        })
+        .unwrap_err();
+        assert!(matches!(err, LocalSignerError::AccountScanLimitReached { max_accounts: 5 }));
+        assert_eq!(calls.get(), 5);
+    }
 }
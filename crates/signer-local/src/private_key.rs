@@ -1,17 +1,28 @@
 //! [`k256`] signer implementation.
 
 use super::{LocalSigner, LocalSignerError};
-use alloy_primitives::{hex, B256};
+#[cfg(feature = "keystore")]
+use alloy_primitives::keccak256;
+#[cfg(feature = "dangerous-testing")]
+use alloy_primitives::Signature;
+use alloy_primitives::{hex, uint, B256, U256};
 use alloy_signer::utils::secret_key_to_address;
 use k256::{
-    ecdsa::{self, SigningKey},
+    ecdsa::{self, SigningKey, VerifyingKey},
+    elliptic_curve::zeroize::Zeroize,
     FieldBytes, NonZeroScalar, SecretKey as K256SecretKey,
 };
 use rand::{CryptoRng, Rng};
 use std::str::FromStr;
 
 #[cfg(feature = "keystore")]
-use std::path::Path;
+use eth_keystore::{
+    CipherparamsJson, CryptoJson, EthKeystore, KdfType, KdfparamsType, KeystoreError,
+};
+#[cfg(feature = "keystore")]
+use k256::elliptic_curve::zeroize::Zeroizing;
+#[cfg(feature = "keystore")]
+use std::{fs, path::Path};
 
 impl LocalSigner<SigningKey> {
     /// Creates a new [`LocalSigner`] instance from a [`SigningKey`].
@@ -51,6 +62,25 @@ impl LocalSigner<SigningKey> {
         SigningKey::from_slice(bytes).map(Self::from_signing_key)
     }
 
+    /// Creates a new [`LocalSigner`] instance from a raw scalar serialized as a byte slice, like
+    /// [`from_slice`](Self::from_slice), but with a specific error for invalid key material.
+    ///
+    /// Returns [`LocalSignerError::InvalidScalar`] if the slice is longer than 32 bytes, or if the
+    /// scalar is zero or not less than the secp256k1 curve order.
+    pub fn from_slice_validated(bytes: &[u8]) -> Result<Self, LocalSignerError> {
+        /// The order of the secp256k1 curve.
+        const SECP256K1_ORDER: U256 =
+            uint!(0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141_U256);
+
+        let reason = match U256::try_from_be_slice(bytes) {
+            None => "key is longer than 32 bytes",
+            Some(scalar) if scalar.is_zero() => "zero key",
+            Some(scalar) if scalar >= SECP256K1_ORDER => "key >= curve order",
+            Some(_) => return Ok(Self::from_slice(bytes)?),
+        };
+        Err(LocalSignerError::InvalidScalar { reason })
+    }
+
     /// Creates a new random keypair seeded with [`rand::thread_rng()`].
     #[inline]
     pub fn random() -> Self {
@@ -63,6 +93,112 @@ impl LocalSigner<SigningKey> {
         Self::from_signing_key(SigningKey::random(rng))
     }
 
+    /// Creates a new [`LocalSigner`] instance from a hex-encoded private key, with or without a
+    /// `0x` prefix, stored in the environment variable `var`.
+    ///
+    /// Surrounding whitespace is ignored. The value read from the environment is zeroized once it
+    /// has been parsed, and is never included in the returned errors.
+    pub fn from_env(var: &str) -> Result<Self, LocalSignerError> {
+        let mut value = std::env::var(var).map_err(|err| match err {
+            std::env::VarError::NotPresent => {
+                LocalSignerError::EnvVarNotPresent { name: var.to_string() }
+            }
+            std::env::VarError::NotUnicode(value) => {
+                value.into_encoded_bytes().zeroize();
+                LocalSignerError::EnvVarNotUnicode { name: var.to_string() }
+            }
+        })?;
+        let signer = value.trim().parse();
+        value.zeroize();
+        signer
+    }
+
+    /// Generates random keys until the lowercase hex address starts with `prefix`, with or
+    /// without a `0x` prefix.
+    ///
+    /// The prefix is matched case-insensitively. See
+    /// [`random_vanity_checksummed`](Self::random_vanity_checksummed) to match against the
+    /// [EIP-55] checksummed address instead.
+    ///
+    /// Every additional hex character multiplies the expected number of attempts by 16, so
+    /// prefixes longer than a handful of characters are impractical. Returns
+    /// [`LocalSignerError::VanityNotFound`] if no match was found in `max_attempts` attempts.
+    ///
+    /// With the `parallel` feature, the search is spread across all available threads.
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    pub fn random_vanity(prefix: &str, max_attempts: usize) -> Result<Self, LocalSignerError> {
+        Self::random_vanity_inner(prefix, max_attempts, false)
+    }
+
+    /// Generates random keys until the [EIP-55] checksummed address starts with `prefix`, with
+    /// or without a `0x` prefix.
+    ///
+    /// The prefix is matched case-sensitively, so every letter additionally halves the chance of
+    /// a match compared to [`random_vanity`](Self::random_vanity).
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    pub fn random_vanity_checksummed(
+        prefix: &str,
+        max_attempts: usize,
+    ) -> Result<Self, LocalSignerError> {
+        Self::random_vanity_inner(prefix, max_attempts, true)
+    }
+
+    fn random_vanity_inner(
+        prefix: &str,
+        max_attempts: usize,
+        checksummed: bool,
+    ) -> Result<Self, LocalSignerError> {
+        let prefix = prefix.strip_prefix("0x").unwrap_or(prefix);
+        if let Some((index, c)) = prefix.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+            return Err(hex::FromHexError::InvalidHexCharacter { c, index }.into());
+        }
+        let lowercase_prefix = prefix.to_ascii_lowercase();
+        let is_match = |signer: &Self| {
+            if checksummed {
+                signer.address.to_checksum(None)[2..].starts_with(prefix)
+            } else {
+                hex::encode(signer.address).starts_with(&lowercase_prefix)
+            }
+        };
+
+        #[cfg(not(feature = "parallel"))]
+        let found = {
+            let mut rng = rand::thread_rng();
+            (0..max_attempts).map(|_| Self::random_with(&mut rng)).find(is_match)
+        };
+
+        #[cfg(feature = "parallel")]
+        let found = {
+            use std::sync::atomic::{AtomicUsize, Ordering};
+
+            let attempts = AtomicUsize::new(0);
+            let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+            std::thread::scope(|scope| {
+                let handles = (0..threads)
+                    .map(|_| {
+                        scope.spawn(|| {
+                            let mut rng = rand::thread_rng();
+                            while attempts.fetch_add(1, Ordering::Relaxed) < max_attempts {
+                                let signer = Self::random_with(&mut rng);
+                                if is_match(&signer) {
+                                    // stop the other threads
+                                    attempts.store(max_attempts, Ordering::Relaxed);
+                                    return Some(signer);
+                                }
+                            }
+                            None
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                handles.into_iter().filter_map(|handle| handle.join().unwrap()).next()
+            })
+        };
+
+        found.ok_or(LocalSignerError::VanityNotFound { attempts: max_attempts })
+    }
+
     /// Borrow the secret [`NonZeroScalar`] value for this key.
     ///
     /// # ⚠️ Warning
@@ -75,6 +211,15 @@ impl LocalSigner<SigningKey> {
         self.credential.as_nonzero_scalar()
     }
 
+    /// Borrow the [`VerifyingKey`] (public key) of this [`LocalSigner`].
+    ///
+    /// The public key is derived once when the signer is constructed and cached alongside the
+    /// [`SigningKey`], so this does not perform any elliptic curve operations.
+    #[inline]
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        self.credential.verifying_key()
+    }
+
     /// Serialize this [`LocalSigner`]'s [`SigningKey`] as a [`B256`] byte array.
     #[inline]
     pub fn to_bytes(&self) -> B256 {
@@ -88,6 +233,39 @@ impl LocalSigner<SigningKey> {
     }
 }
 
+#[cfg(feature = "dangerous-testing")]
+impl LocalSigner<SigningKey> {
+    /// Signs the given hash using the caller-supplied ECDSA nonce `k`, instead of deriving it
+    /// deterministically from the key and hash as specified in [RFC 6979].
+    ///
+    /// This is only meant for reproducing known test vectors and debugging, and is gated behind
+    /// the `dangerous-testing` feature.
+    ///
+    /// # ⚠️ Warning
+    ///
+    /// **Never use this in production.** The security of ECDSA relies entirely on `k` being
+    /// secret and unique per signature: signing two different hashes with the same `k`, or with a
+    /// `k` that is known or predictable, allows anyone to compute the private key from the
+    /// signatures.
+    ///
+    /// [RFC 6979]: https://datatracker.ietf.org/doc/html/rfc6979
+    pub fn sign_hash_with_k_sync(&self, hash: &B256, k: &B256) -> alloy_signer::Result<Signature> {
+        use k256::{ecdsa::hazmat::SignPrimitive, elliptic_curve::PrimeField, Scalar};
+
+        self.check_blind_signing()?;
+        if let Some(hook) = &self.audit_hook {
+            hook(hash);
+        }
+
+        let k =
+            Option::<Scalar>::from(Scalar::from_repr(k.0.into())).ok_or_else(ecdsa::Error::new)?;
+        let scalar: &Scalar = self.credential.as_nonzero_scalar().as_ref();
+        let (sig, recovery_id) = scalar.try_sign_prehashed(k, &hash.0.into())?;
+        let recovery_id = recovery_id.ok_or_else(ecdsa::Error::new)?;
+        Ok(Signature::from_signature_and_parity(sig, recovery_id)?)
+    }
+}
+
 #[cfg(feature = "keystore")]
 impl LocalSigner<SigningKey> {
     /// Creates a new random encrypted JSON with the provided password and stores it in the
@@ -110,6 +288,75 @@ impl LocalSigner<SigningKey> {
         Ok((Self::from_slice(&secret)?, uuid))
     }
 
+    /// Creates `count` new random encrypted JSONs sharing the provided password and stores them in
+    /// the provided directory, as with [`new_keystore`](Self::new_keystore). Returns the signer
+    /// instance and random UUID of every keystore, in creation order.
+    ///
+    /// `progress` is called with the number of keystores created so far after each one is
+    /// written. With the `parallel` feature, the key derivation is spread across all available
+    /// threads.
+    pub fn create_keystores<P, R, S>(
+        dir: P,
+        count: usize,
+        rng: &mut R,
+        password: S,
+        mut progress: impl FnMut(usize),
+    ) -> Result<Vec<(Self, String)>, LocalSignerError>
+    where
+        P: AsRef<Path>,
+        R: Rng + CryptoRng,
+        S: AsRef<[u8]>,
+    {
+        let (dir, password) = (dir.as_ref(), password.as_ref());
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            let mut keystores = Vec::with_capacity(count);
+            for created in 1..=count {
+                keystores.push(Self::new_keystore(dir, rng, password, None)?);
+                progress(created);
+            }
+            Ok(keystores)
+        }
+
+        #[cfg(feature = "parallel")]
+        {
+            use rand::{rngs::StdRng, SeedableRng};
+            use std::sync::{
+                atomic::{AtomicUsize, Ordering},
+                mpsc,
+            };
+
+            // every keystore gets its own rng, seeded from the caller's
+            let seeds = (0..count).map(|_| rng.gen()).collect::<Vec<_>>();
+            let next = AtomicUsize::new(0);
+            let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+            let mut results = (0..count).map(|_| None).collect::<Vec<_>>();
+            std::thread::scope(|scope| {
+                let (tx, rx) = mpsc::channel();
+                for _ in 0..threads.min(count) {
+                    let (tx, seeds, next) = (tx.clone(), &seeds, &next);
+                    scope.spawn(move || loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(&seed) = seeds.get(index) else { break };
+                        let mut rng = StdRng::from_seed(seed);
+                        let result = Self::new_keystore(dir, &mut rng, password, None);
+                        if tx.send((index, result)).is_err() {
+                            break;
+                        }
+                    });
+                }
+                drop(tx);
+
+                for (created, (index, result)) in rx.iter().enumerate() {
+                    results[index] = Some(result);
+                    progress(created + 1);
+                }
+            });
+            results.into_iter().map(|result| result.expect("every keystore was created")).collect()
+        }
+    }
+
     /// Decrypts an encrypted JSON from the provided path to construct a [`LocalSigner`] instance
     #[inline]
     pub fn decrypt_keystore<P, S>(keypath: P, password: S) -> Result<Self, LocalSignerError>
@@ -143,6 +390,148 @@ impl LocalSigner<SigningKey> {
         let uuid = eth_keystore::encrypt_key(keypath, rng, pk, password, name)?;
         Ok((Self::from_slice(pk)?, uuid))
     }
+
+    /// Creates a new encrypted JSON with the provided private key and password using the
+    /// [PBKDF2](https://datatracker.ietf.org/doc/html/rfc8018) key derivation function with the
+    /// given number of iterations, and stores it in the provided directory. Returns a tuple
+    /// (LocalSigner, String) of the signer instance for the keystore with its random UUID, which is
+    /// also used as the name of the keystore file.
+    ///
+    /// Prefer [`encrypt_keystore`](Self::encrypt_keystore), which uses scrypt, unless the keystore
+    /// has to be read by tools that only support PBKDF2.
+    ///
+    /// Returns [`LocalSignerError::ZeroIterations`] if `iterations` is `0`.
+    pub fn encrypt_keystore_pbkdf2<P, R, B, S>(
+        dir: P,
+        rng: &mut R,
+        pk: B,
+        password: S,
+        iterations: u32,
+    ) -> Result<(Self, String), LocalSignerError>
+    where
+        P: AsRef<Path>,
+        R: Rng + CryptoRng,
+        B: AsRef<[u8]>,
+        S: AsRef<[u8]>,
+    {
+        use aes::cipher::{KeyIvInit, StreamCipher};
+
+        if iterations == 0 {
+            return Err(LocalSignerError::ZeroIterations);
+        }
+        let pk = pk.as_ref();
+        let signer = Self::from_slice(pk)?;
+
+        let salt: [u8; 32] = rng.gen();
+        let mut key = Zeroizing::new([0u8; 32]);
+        pbkdf2::pbkdf2_hmac::<sha2::Sha256>(password.as_ref(), &salt, iterations, &mut *key);
+
+        let iv: [u8; 16] = rng.gen();
+        let mut ciphertext = pk.to_vec();
+        ctr::Ctr128BE::<aes::Aes128>::new(key[..16].into(), &iv.into())
+            .apply_keystream(&mut ciphertext);
+        let mac = keccak256(Zeroizing::new([&key[16..], &ciphertext[..]].concat()));
+
+        let crypto = CryptoJson {
+            cipher: "aes-128-ctr".into(),
+            cipherparams: CipherparamsJson { iv: iv.to_vec() },
+            ciphertext,
+            kdf: KdfType::Pbkdf2,
+            kdfparams: KdfparamsType::Pbkdf2 {
+                c: iterations,
+                dklen: key.len() as u8,
+                prf: "hmac-sha256".into(),
+                salt: salt.to_vec(),
+            },
+            mac: mac.to_vec(),
+        };
+        let uuid = random_uuid(rng);
+        let keystore = serde_json::json!({ "crypto": crypto, "id": uuid, "version": 3 });
+        fs::write(dir.as_ref().join(&uuid), keystore.to_string())?;
+
+        Ok((signer, uuid))
+    }
+
+    /// Re-encrypts the encrypted JSON at the provided path with a new password, preserving its
+    /// UUID.
+    ///
+    /// The keystore is first decrypted with the old password. The re-encrypted keystore is then
+    /// written to a temporary file in the same directory, which is renamed over the original so
+    /// that the keystore is never left partially written.
+    pub fn reencrypt_keystore<P, S, T, R>(
+        keypath: P,
+        old_password: S,
+        new_password: T,
+        rng: &mut R,
+    ) -> Result<(), LocalSignerError>
+    where
+        P: AsRef<Path>,
+        S: AsRef<[u8]>,
+        T: AsRef<[u8]>,
+        R: Rng + CryptoRng,
+    {
+        let keypath = keypath.as_ref();
+        let secret = Zeroizing::new(eth_keystore::decrypt_key(keypath, old_password)?);
+        let id = read_keystore(keypath)?.id;
+
+        let dir = keypath.parent().unwrap_or_else(|| Path::new(""));
+        let name = keypath.file_name().ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::InvalidInput, "keystore path has no file name")
+        })?;
+        let tmp_name = format!(".{}.tmp", name.to_string_lossy());
+        let tmp_path = dir.join(&tmp_name);
+
+        eth_keystore::encrypt_key(dir, rng, secret.as_slice(), new_password, Some(&tmp_name))?;
+        let result = (|| -> Result<(), LocalSignerError> {
+            let mut keystore = read_keystore(&tmp_path)?;
+            keystore.id = id;
+            let contents = serde_json::to_vec(&keystore).map_err(KeystoreError::from)?;
+            fs::write(&tmp_path, contents)?;
+            fs::rename(&tmp_path, keypath)?;
+            Ok(())
+        })();
+        if result.is_err() {
+            let _ = fs::remove_file(&tmp_path);
+        }
+        result
+    }
+}
+
+/// Generates a random version 4 UUID in its hyphenated string form.
+#[cfg(feature = "keystore")]
+fn random_uuid<R: Rng>(rng: &mut R) -> String {
+    let mut bytes: [u8; 16] = rng.gen();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = hex::encode(bytes);
+    format!("{}-{}-{}-{}-{}", &hex[..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..])
+}
+
+#[cfg(feature = "keystore")]
+fn read_keystore(path: &Path) -> Result<EthKeystore, LocalSignerError> {
+    let contents = fs::read(path)?;
+    Ok(serde_json::from_slice(&contents).map_err(KeystoreError::from)?)
+}
+
+/// Returns the index pairs `(i, j)`, with `i < j`, of the signers sharing the same signing key,
+/// e.g. to detect a key imported twice into a validator set.
+///
+/// Keys are compared by their [`to_bytes`](SigningKey::to_bytes) encoding, so signers with
+/// different chain IDs or labels are still reported. The pairs are sorted, and a key shared by
+/// `n` signers yields all of their `n * (n - 1) / 2` pairs.
+pub fn find_duplicate_keys(signers: &[LocalSigner<SigningKey>]) -> Vec<(usize, usize)> {
+    let mut keys: Vec<FieldBytes> =
+        signers.iter().map(|signer| signer.credential.to_bytes()).collect();
+    let mut duplicates = Vec::new();
+    for (i, key) in keys.iter().enumerate() {
+        for (j, other) in keys.iter().enumerate().skip(i + 1) {
+            if key == other {
+                duplicates.push((i, j));
+            }
+        }
+    }
+    keys.zeroize();
+    duplicates
 }
 
 impl PartialEq for LocalSigner<SigningKey> {
@@ -177,7 +566,7 @@ impl FromStr for LocalSigner<SigningKey> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{PrivateKeySigner, SignerSync};
+    use crate::{PrivateKeySigner, Signer, SignerSync};
     use alloy_primitives::{address, b256};
 
     #[cfg(feature = "keystore")]
@@ -200,6 +589,64 @@ mod tests {
         }
     }
 
+    #[test]
+    fn from_slice_validated() {
+        let key = hex!("4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318");
+        let signer = PrivateKeySigner::from_slice_validated(&key).unwrap();
+        assert_eq!(signer.address, address!("2c7536E3605D9C16a7a3D7b1898e529396a65c23"));
+
+        let err = PrivateKeySigner::from_slice_validated(&[0; 32]).unwrap_err();
+        assert!(matches!(err, LocalSignerError::InvalidScalar { reason: "zero key" }));
+        assert_eq!(err.to_string(), "invalid private key: zero key");
+
+        let err = PrivateKeySigner::from_slice_validated(&[0xff; 32]).unwrap_err();
+        assert!(matches!(err, LocalSignerError::InvalidScalar { reason: "key >= curve order" }));
+        let order = hex!("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141");
+        assert!(PrivateKeySigner::from_slice_validated(&order).is_err());
+        let mut max = order;
+        max[31] -= 1;
+        assert!(PrivateKeySigner::from_slice_validated(&max).is_ok());
+
+        assert!(matches!(
+            PrivateKeySigner::from_slice_validated(&[1; 33]),
+            Err(LocalSignerError::InvalidScalar { reason: "key is longer than 32 bytes" })
+        ));
+    }
+
+    #[test]
+    fn from_env() {
+        let var = "ALLOY_SIGNER_LOCAL_TEST_FROM_ENV";
+        std::env::set_var(
+            var,
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318\n",
+        );
+        let signer = PrivateKeySigner::from_env(var).unwrap();
+        assert_eq!(signer.address, address!("2c7536E3605D9C16a7a3D7b1898e529396a65c23"));
+
+        std::env::set_var(var, "not a key");
+        assert!(matches!(PrivateKeySigner::from_env(var), Err(LocalSignerError::HexError(_))));
+
+        #[cfg(unix)]
+        {
+            use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+            let secret = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+            let mut value = secret.as_bytes().to_vec();
+            value.push(0xff);
+            std::env::set_var(var, OsStr::from_bytes(&value));
+            let err = PrivateKeySigner::from_env(var).unwrap_err();
+            assert!(matches!(&err, LocalSignerError::EnvVarNotUnicode { name } if name == var));
+            assert!(!err.to_string().contains(secret));
+            assert!(!format!("{err:?}").contains(secret));
+        }
+
+        std::env::remove_var(var);
+        assert!(matches!(
+            PrivateKeySigner::from_env(var),
+            Err(LocalSignerError::EnvVarNotPresent { name }) if name == var
+        ));
+    }
+
     #[cfg(feature = "keystore")]
     fn test_encrypted_json_keystore(key: LocalSigner<SigningKey>, uuid: &str, dir: &Path) {
         // sign a message using the given key
@@ -252,6 +699,107 @@ mod tests {
         test_encrypted_json_keystore(key, &uuid, dir.path());
     }
 
+    #[test]
+    #[cfg(feature = "keystore")]
+    fn encrypted_json_keystore_pbkdf2() {
+        let dir = tempdir().unwrap();
+        let mut rng = rand::thread_rng();
+
+        let private_key =
+            hex::decode("6f142508b4eea641e33cb2a0161221105086a84584c74245ca463a49effea30b")
+                .unwrap();
+
+        let (key, uuid) = LocalSigner::<SigningKey>::encrypt_keystore_pbkdf2(
+            &dir,
+            &mut rng,
+            private_key,
+            "randpsswd",
+            1024,
+        )
+        .unwrap();
+
+        let keystore = read_keystore(&dir.path().join(&uuid)).unwrap();
+        assert_eq!(keystore.id.to_string(), uuid);
+        assert_eq!(keystore.id.get_version_num(), 4);
+        assert_eq!(keystore.version, 3);
+        assert_eq!(keystore.crypto.kdf, KdfType::Pbkdf2);
+        assert!(matches!(
+            keystore.crypto.kdfparams,
+            KdfparamsType::Pbkdf2 { c: 1024, dklen: 32, ref prf, .. } if prf == "hmac-sha256"
+        ));
+
+        test_encrypted_json_keystore(key, &uuid, dir.path());
+
+        let files = fs::read_dir(&dir).unwrap().count();
+        let err = LocalSigner::<SigningKey>::encrypt_keystore_pbkdf2(
+            &dir,
+            &mut rng,
+            B256::with_last_byte(1),
+            "randpsswd",
+            0,
+        )
+        .unwrap_err();
+        assert!(matches!(err, LocalSignerError::ZeroIterations));
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), files);
+    }
+
+    #[test]
+    #[cfg(feature = "keystore")]
+    fn create_keystores() {
+        let dir = tempdir().unwrap();
+        let mut rng = rand::thread_rng();
+        let mut progress = Vec::new();
+        let keystores = LocalSigner::<SigningKey>::create_keystores(
+            &dir,
+            3,
+            &mut rng,
+            "randpsswd",
+            |created| progress.push(created),
+        )
+        .unwrap();
+        assert_eq!(progress, [1, 2, 3]);
+        assert_eq!(keystores.len(), 3);
+        assert_ne!(keystores[0].0, keystores[1].0);
+        assert_ne!(keystores[1].1, keystores[2].1);
+
+        for (key, uuid) in keystores {
+            test_encrypted_json_keystore(key, &uuid, dir.path());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "keystore")]
+    fn reencrypt_keystore() {
+        let dir = tempdir().unwrap();
+        let mut rng = rand::thread_rng();
+        let (key, uuid) =
+            LocalSigner::<SigningKey>::new_keystore(&dir, &mut rng, "oldpsswd", None).unwrap();
+        let path = dir.path().join(&uuid);
+
+        LocalSigner::<SigningKey>::reencrypt_keystore(&path, "oldpsswd", "randpsswd", &mut rng)
+            .unwrap();
+        assert!(LocalSigner::<SigningKey>::decrypt_keystore(&path, "oldpsswd").is_err());
+        assert_eq!(read_keystore(&path).unwrap().id.to_string(), uuid);
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+
+        test_encrypted_json_keystore(key, &uuid, dir.path());
+    }
+
+    #[test]
+    #[cfg(feature = "keystore")]
+    fn reencrypt_keystore_wrong_password() {
+        let dir = tempdir().unwrap();
+        let mut rng = rand::thread_rng();
+        let (_, uuid) =
+            LocalSigner::<SigningKey>::new_keystore(&dir, &mut rng, "randpsswd", None).unwrap();
+        let path = dir.path().join(&uuid);
+
+        assert!(
+            LocalSigner::<SigningKey>::reencrypt_keystore(&path, "wrong", "new", &mut rng).is_err()
+        );
+        assert!(LocalSigner::<SigningKey>::decrypt_keystore(&path, "randpsswd").is_ok());
+    }
+
     #[test]
     fn signs_msg() {
         let message = "Some data";
@@ -370,6 +918,82 @@ mod tests {
         assert_eq!(signer_field_bytes.to_field_bytes(), key.0.into());
     }
 
+    #[test]
+    fn cached_verifying_key() {
+        let signer = PrivateKeySigner::random();
+        let derived =
+            VerifyingKey::from(&SigningKey::from_bytes(&signer.to_field_bytes()).unwrap());
+        assert_eq!(*signer.verifying_key(), derived);
+        assert_eq!(alloy_signer::utils::public_key_to_address(&derived), signer.address());
+    }
+
+    #[test]
+    #[cfg(feature = "dangerous-testing")]
+    fn sign_hash_with_k() {
+        use alloy_primitives::U256;
+
+        // With `d = 1` and `k = 1`, `R = G` and `s = z + r`.
+        let signer = PrivateKeySigner::from_bytes(&B256::with_last_byte(1)).unwrap();
+        let hash = B256::with_last_byte(1);
+        let sig = signer.sign_hash_with_k_sync(&hash, &B256::with_last_byte(1)).unwrap();
+
+        let gx = b256!("79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798");
+        assert_eq!(sig.r(), U256::from_be_bytes(gx.0));
+        assert_eq!(sig.s(), U256::from_be_bytes(gx.0) + U256::from(1));
+        assert!(!sig.v().y_parity());
+        assert_eq!(sig.recover_address_from_prehash(&hash).unwrap(), signer.address());
+
+        assert!(signer.sign_hash_with_k_sync(&hash, &B256::ZERO).is_err());
+    }
+
+    #[test]
+    fn equality_ignores_label() {
+        let signer = PrivateKeySigner::random();
+        let labeled = signer.clone().with_label("treasury");
+        assert_eq!(labeled.label(), Some("treasury"));
+        assert_eq!(signer.label(), None);
+        assert_eq!(signer, labeled);
+        assert_eq!(signer.clone().with_label("hot wallet"), labeled);
+        assert_ne!(PrivateKeySigner::random().with_label("treasury"), labeled);
+    }
+
+    #[test]
+    fn random_vanity() {
+        let signer = PrivateKeySigner::random_vanity("0xa", 10_000).unwrap();
+        assert!(hex::encode(signer.address()).starts_with('a'));
+
+        let signer = PrivateKeySigner::random_vanity_checksummed("B", 10_000).unwrap();
+        assert!(signer.address().to_checksum(None).starts_with("0xB"));
+
+        // a full address is never found
+        let prefix = "00".repeat(20);
+        assert!(matches!(
+            PrivateKeySigner::random_vanity(&prefix, 10),
+            Err(LocalSignerError::VanityNotFound { attempts: 10 })
+        ));
+
+        assert!(matches!(
+            PrivateKeySigner::random_vanity("g", 10),
+            Err(LocalSignerError::HexError(hex::FromHexError::InvalidHexCharacter {
+                c: 'g',
+                index: 0
+            }))
+        ));
+    }
+
+    #[test]
+    fn duplicate_keys() {
+        let signer = PrivateKeySigner::random();
+        let signers = [
+            signer.clone(),
+            PrivateKeySigner::random(),
+            PrivateKeySigner::from_signing_key(signer.credential.clone()).with_chain_id(Some(5)),
+        ];
+        assert_eq!(find_duplicate_keys(&signers), [(0, 2)]);
+        assert_eq!(find_duplicate_keys(&signers[..2]), []);
+        assert_eq!(find_duplicate_keys(&[]), []);
+    }
+
     #[test]
     fn key_from_str() {
         let signer: LocalSigner<SigningKey> =
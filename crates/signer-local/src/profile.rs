@@ -0,0 +1,239 @@
+use crate::{LocalSignerError, PrivateKeySigner};
+use alloy_consensus::{
+    SignableTransaction, TxEip1559, TxEnvelope, TxLegacy, TxType, TypedTransaction,
+};
+use alloy_primitives::{ChainId, Signature};
+use std::collections::BTreeMap;
+
+/// The transaction form to sign on a chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TxForm {
+    /// [EIP-155] legacy transactions, e.g. for chains without a fee market.
+    ///
+    /// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+    Legacy,
+    /// [EIP-1559] fee market transactions. [EIP-4844] transactions are kept as-is.
+    ///
+    /// [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+    /// [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
+    Eip1559,
+}
+
+/// Signing preferences of a chain in a [`WalletProfile`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ChainPrefs {
+    /// The transaction form to convert transactions to before signing, or `None` to sign them
+    /// as-is.
+    pub tx_form: Option<TxForm>,
+}
+
+impl ChainPrefs {
+    /// Preferences forcing [EIP-155] legacy transactions.
+    ///
+    /// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+    pub const fn legacy() -> Self {
+        Self { tx_form: Some(TxForm::Legacy) }
+    }
+
+    /// Preferences forcing [EIP-1559] transactions.
+    ///
+    /// [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+    pub const fn eip1559() -> Self {
+        Self { tx_form: Some(TxForm::Eip1559) }
+    }
+}
+
+/// A signer together with per-chain signing preferences, e.g. for a cross-chain relayer using
+/// one key on several chains.
+///
+/// See [`sign_for_chain`](Self::sign_for_chain).
+#[derive(Clone, Debug)]
+pub struct WalletProfile {
+    /// The signer.
+    pub wallet: PrivateKeySigner,
+    /// The signing preferences of each supported chain.
+    pub chains: BTreeMap<ChainId, ChainPrefs>,
+}
+
+impl WalletProfile {
+    /// Creates a new profile without any supported chain.
+    pub const fn new(wallet: PrivateKeySigner) -> Self {
+        Self { wallet, chains: BTreeMap::new() }
+    }
+
+    /// Adds a supported chain with the given preferences.
+    pub fn with_chain(mut self, chain_id: ChainId, prefs: ChainPrefs) -> Self {
+        self.chains.insert(chain_id, prefs);
+        self
+    }
+
+    /// Signs the transaction for the given chain, after converting it to the chain's preferred
+    /// [`TxForm`] and setting its chain ID.
+    ///
+    /// The chain ID of the [signer](Self::wallet) itself is ignored. When converting:
+    /// - to [`TxForm::Legacy`], the max fee per gas becomes the gas price and the access list is
+    ///   dropped. [EIP-4844] transactions cannot be converted and return an error.
+    /// - to [`TxForm::Eip1559`], the gas price becomes both the max fee and the max priority fee
+    ///   per gas.
+    ///
+    /// Returns an error if the chain is not part of the profile.
+    ///
+    /// [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
+    pub fn sign_for_chain(
+        &self,
+        chain_id: ChainId,
+        tx: impl Into<TypedTransaction>,
+    ) -> Result<TxEnvelope, LocalSignerError> {
+        let prefs =
+            self.chains.get(&chain_id).ok_or(LocalSignerError::UnsupportedChain { chain_id })?;
+        let tx = match prefs.tx_form {
+            Some(form) => convert(tx.into(), form)?,
+            None => tx.into(),
+        };
+        match tx {
+            TypedTransaction::Legacy(t) => self.sign(chain_id, t),
+            TypedTransaction::Eip2930(t) => self.sign(chain_id, t),
+            TypedTransaction::Eip1559(t) => self.sign(chain_id, t),
+            TypedTransaction::Eip4844(t) => self.sign(chain_id, t),
+        }
+    }
+
+    fn sign<T>(&self, chain_id: ChainId, mut tx: T) -> Result<TxEnvelope, LocalSignerError>
+    where
+        T: SignableTransaction<Signature>,
+        alloy_consensus::Signed<T>: Into<TxEnvelope>,
+    {
+        self.wallet.check_value(tx.value())?;
+        tx.set_chain_id(chain_id);
+        let signature =
+            self.wallet.sign_structured_hash(&tx.signature_hash()).map_err(|source| {
+                LocalSignerError::SigningError {
+                    context: format!("sign_for_chain({chain_id})"),
+                    source,
+                }
+            })?;
+        Ok(tx.into_signed(signature).into())
+    }
+}
+
+fn convert(tx: TypedTransaction, form: TxForm) -> Result<TypedTransaction, LocalSignerError> {
+    Ok(match (tx, form) {
+        (TypedTransaction::Eip2930(t), TxForm::Legacy) => TxLegacy {
+            chain_id: Some(t.chain_id),
+            nonce: t.nonce,
+            gas_price: t.gas_price,
+            gas_limit: t.gas_limit,
+            to: t.to,
+            value: t.value,
+            input: t.input,
+        }
+        .into(),
+        (TypedTransaction::Eip1559(t), TxForm::Legacy) => TxLegacy {
+            chain_id: Some(t.chain_id),
+            nonce: t.nonce,
+            gas_price: t.max_fee_per_gas,
+            gas_limit: t.gas_limit,
+            to: t.to,
+            value: t.value,
+            input: t.input,
+        }
+        .into(),
+        (TypedTransaction::Eip4844(_), TxForm::Legacy) => {
+            return Err(LocalSignerError::IncompatibleTxForm { tx_type: TxType::Eip4844, form })
+        }
+        (TypedTransaction::Legacy(t), TxForm::Eip1559) => TxEip1559 {
+            chain_id: t.chain_id.unwrap_or_default(),
+            nonce: t.nonce,
+            gas_limit: t.gas_limit,
+            max_fee_per_gas: t.gas_price,
+            max_priority_fee_per_gas: t.gas_price,
+            to: t.to,
+            value: t.value,
+            access_list: Default::default(),
+            input: t.input,
+        }
+        .into(),
+        (TypedTransaction::Eip2930(t), TxForm::Eip1559) => TxEip1559 {
+            chain_id: t.chain_id,
+            nonce: t.nonce,
+            gas_limit: t.gas_limit,
+            max_fee_per_gas: t.gas_price,
+            max_priority_fee_per_gas: t.gas_price,
+            to: t.to,
+            value: t.value,
+            access_list: t.access_list,
+            input: t.input,
+        }
+        .into(),
+        (tx, _) => tx,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{Signed, Transaction, TxEip4844};
+    use alloy_primitives::{address, Address, U256};
+    use alloy_signer::Signer;
+
+    fn recover<T: SignableTransaction<Signature>>(signed: &Signed<T>) -> Address {
+        signed.signature().recover_address_from_prehash(&signed.tx().signature_hash()).unwrap()
+    }
+
+    #[test]
+    fn sign_for_chain() {
+        let wallet = PrivateKeySigner::random().with_chain_id(Some(10));
+        let profile = WalletProfile::new(wallet.clone())
+            .with_chain(1, ChainPrefs::eip1559())
+            .with_chain(56, ChainPrefs::legacy())
+            .with_chain(137, ChainPrefs::default());
+        let tx = TxEip1559 {
+            nonce: 3,
+            gas_limit: 21_000,
+            max_fee_per_gas: 50,
+            max_priority_fee_per_gas: 2,
+            to: address!("6069a6c32cf691f5982febae4faf8a6f3ab2f0f6").into(),
+            value: U256::from(1),
+            ..Default::default()
+        };
+        let legacy = TxLegacy { gas_price: 20, gas_limit: 21_000, ..Default::default() };
+
+        let TxEnvelope::Legacy(signed) = profile.sign_for_chain(56, tx.clone()).unwrap() else {
+            panic!("expected a legacy transaction");
+        };
+        assert_eq!(signed.tx().chain_id, Some(56));
+        assert_eq!(signed.tx().gas_price, 50);
+        assert_eq!(signed.tx().to, tx.to);
+        assert_eq!(recover(&signed), wallet.address());
+
+        let TxEnvelope::Eip1559(signed) = profile.sign_for_chain(1, legacy).unwrap() else {
+            panic!("expected an EIP-1559 transaction");
+        };
+        assert_eq!(signed.tx().chain_id, 1);
+        assert_eq!(signed.tx().max_fee_per_gas, 20);
+        assert_eq!(signed.tx().max_priority_fee_per_gas(), Some(20));
+        assert_eq!(recover(&signed), wallet.address());
+
+        let TxEnvelope::Eip1559(signed) = profile.sign_for_chain(1, tx.clone()).unwrap() else {
+            panic!("expected an EIP-1559 transaction");
+        };
+        assert_eq!(signed.tx(), &TxEip1559 { chain_id: 1, ..tx.clone() });
+
+        let TxEnvelope::Eip1559(signed) = profile.sign_for_chain(137, tx.clone()).unwrap() else {
+            panic!("expected an EIP-1559 transaction");
+        };
+        assert_eq!(signed.tx().chain_id, 137);
+
+        assert!(matches!(
+            profile.sign_for_chain(5, tx),
+            Err(LocalSignerError::UnsupportedChain { chain_id: 5 })
+        ));
+        assert!(matches!(
+            profile.sign_for_chain(56, TxEip4844::default()),
+            Err(LocalSignerError::IncompatibleTxForm {
+                tx_type: TxType::Eip4844,
+                form: TxForm::Legacy
+            })
+        ));
+    }
+}
@@ -0,0 +1,193 @@
+//! [BIP-32] hierarchical deterministic key derivation paths.
+//!
+//! [BIP-32]: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki
+
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// The offset added to a child index to mark it as hardened.
+pub const HARDENED_OFFSET: u32 = 1 << 31;
+
+/// Error returned when parsing or building a [`DerivationPath`].
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum DerivationPathError {
+    /// The path does not start with the master key marker `m`.
+    #[error("derivation path must start with `m`")]
+    MissingMasterKey,
+    /// A segment of the path is not a decimal index with an optional hardened marker.
+    #[error("invalid derivation path segment `{0}`")]
+    InvalidSegment(String),
+    /// A child index is not below [`HARDENED_OFFSET`].
+    #[error("child index {0} is out of range, must be less than 2^31")]
+    IndexOutOfRange(u32),
+}
+
+/// A [BIP-32] derivation path, such as `m/44'/60'/0'/0/0`.
+///
+/// Each segment is stored as a raw child index, with hardened segments offset by
+/// [`HARDENED_OFFSET`]. Hardened segments are parsed from either a `'` or an `h` suffix and are
+/// always displayed with `'`.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_signer::derivation::{DerivationPath, HARDENED_OFFSET};
+///
+/// let path: DerivationPath = "m/44'/60'/0'/0".parse()?;
+/// let path = path.child(0, false)?;
+/// assert_eq!(path.to_string(), "m/44'/60'/0'/0/0");
+/// assert_eq!(path.indices()[0], 44 + HARDENED_OFFSET);
+/// # Ok::<(), alloy_signer::derivation::DerivationPathError>(())
+/// ```
+///
+/// [BIP-32]: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DerivationPath {
+    indices: Vec<u32>,
+}
+
+impl DerivationPath {
+    /// Creates a new path pointing at the master key, i.e. `m`.
+    pub const fn new() -> Self {
+        Self { indices: Vec::new() }
+    }
+
+    /// Returns the raw child indices of the path, with hardened segments offset by
+    /// [`HARDENED_OFFSET`].
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+
+    /// Returns the number of segments in the path.
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns `true` if the path points at the master key.
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Appends a segment to the path.
+    ///
+    /// Returns an error if `index` is not below [`HARDENED_OFFSET`].
+    pub fn push(&mut self, index: u32, hardened: bool) -> Result<(), DerivationPathError> {
+        if index >= HARDENED_OFFSET {
+            return Err(DerivationPathError::IndexOutOfRange(index));
+        }
+        self.indices.push(if hardened { index + HARDENED_OFFSET } else { index });
+        Ok(())
+    }
+
+    /// Returns the path of the given child of this path.
+    ///
+    /// See [`push`](Self::push) for the accepted indices.
+    pub fn child(&self, index: u32, hardened: bool) -> Result<Self, DerivationPathError> {
+        let mut child = self.clone();
+        child.push(index, hardened)?;
+        Ok(child)
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = DerivationPathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut segments = s.split('/');
+        if segments.next() != Some("m") {
+            return Err(DerivationPathError::MissingMasterKey);
+        }
+
+        let mut path = Self::new();
+        for segment in segments {
+            let (index, hardened) =
+                segment.strip_suffix(['\'', 'h']).map_or((segment, false), |index| (index, true));
+            if index.is_empty() || !index.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(DerivationPathError::InvalidSegment(segment.to_string()));
+            }
+            let index = index
+                .parse()
+                .map_err(|_| DerivationPathError::InvalidSegment(segment.to_string()))?;
+            path.push(index, hardened)?;
+        }
+        Ok(path)
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("m")?;
+        for &index in &self.indices {
+            if index >= HARDENED_OFFSET {
+                write!(f, "/{}'", index - HARDENED_OFFSET)?;
+            } else {
+                write!(f, "/{index}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_canonical_paths() {
+        let path: DerivationPath = "m/44'/60'/0'/0/0".parse().unwrap();
+        assert_eq!(
+            path.indices(),
+            [44 + HARDENED_OFFSET, 60 + HARDENED_OFFSET, HARDENED_OFFSET, 0, 0]
+        );
+        assert_eq!(path.to_string(), "m/44'/60'/0'/0/0");
+
+        let hardened_h: DerivationPath = "m/44h/60h/0h/0/0".parse().unwrap();
+        assert_eq!(hardened_h, path);
+
+        let master: DerivationPath = "m".parse().unwrap();
+        assert!(master.is_empty());
+        assert_eq!(master, DerivationPath::new());
+        assert_eq!(master.to_string(), "m");
+
+        let max: DerivationPath = "m/2147483647/2147483647'".parse().unwrap();
+        assert_eq!(max.indices(), [HARDENED_OFFSET - 1, u32::MAX]);
+    }
+
+    #[test]
+    fn reject_malformed_paths() {
+        for (path, err) in [
+            ("", DerivationPathError::MissingMasterKey),
+            ("44'/60'", DerivationPathError::MissingMasterKey),
+            ("M/44'", DerivationPathError::MissingMasterKey),
+            ("m/", DerivationPathError::InvalidSegment(String::new())),
+            ("m/44'/", DerivationPathError::InvalidSegment(String::new())),
+            ("m//0", DerivationPathError::InvalidSegment(String::new())),
+            ("m/'", DerivationPathError::InvalidSegment("'".into())),
+            ("m/0''", DerivationPathError::InvalidSegment("0''".into())),
+            ("m/+1", DerivationPathError::InvalidSegment("+1".into())),
+            ("m/x", DerivationPathError::InvalidSegment("x".into())),
+            ("m/4294967296", DerivationPathError::InvalidSegment("4294967296".into())),
+            ("m/2147483648", DerivationPathError::IndexOutOfRange(HARDENED_OFFSET)),
+            ("m/2147483648'", DerivationPathError::IndexOutOfRange(HARDENED_OFFSET)),
+        ] {
+            assert_eq!(path.parse::<DerivationPath>(), Err(err), "{path}");
+        }
+    }
+
+    #[test]
+    fn push_child() {
+        let mut path = DerivationPath::new();
+        path.push(44, true).unwrap();
+        path.push(60, true).unwrap();
+        let child = path.child(0, false).unwrap();
+        assert_eq!(path.to_string(), "m/44'/60'");
+        assert_eq!(child.to_string(), "m/44'/60'/0");
+        assert_eq!(child.len(), 3);
+
+        assert_eq!(
+            path.push(HARDENED_OFFSET, false),
+            Err(DerivationPathError::IndexOutOfRange(HARDENED_OFFSET))
+        );
+        assert_eq!(path.len(), 2);
+    }
+}
@@ -1,4 +1,4 @@
-use alloy_primitives::{hex, ChainId};
+use alloy_primitives::{hex, Address, ChainId};
 use k256::ecdsa;
 use std::fmt;
 use thiserror::Error;
@@ -22,6 +22,14 @@ pub enum Error {
         /// The chain ID provided by the transaction.
         tx: ChainId,
     },
+    /// The signature was not produced by the expected address.
+    #[error("signature was produced by {recovered}, expected {expected}")]
+    AddressMismatch {
+        /// The expected signer address.
+        expected: Address,
+        /// The address recovered from the signature.
+        recovered: Address,
+    },
     /// [`alloy_dyn_abi`] error.
     #[error(transparent)]
     #[cfg(feature = "eip712")]
@@ -9,9 +9,16 @@
 mod error;
 pub use error::{Error, Result, UnsupportedSignerOperation};
 
+pub mod derivation;
+
+mod retry;
+pub use retry::RetryingSigner;
+
 mod signer;
 pub use signer::{Signer, SignerSync};
 
+pub mod siwe;
+
 pub mod utils;
 
 pub use alloy_primitives::Signature;
@@ -0,0 +1,187 @@
+use crate::{Error, Result, SignerSync};
+use alloy_primitives::{ChainId, B256};
+use std::{thread, time::Duration};
+
+#[cfg(feature = "eip712")]
+use alloy_dyn_abi::eip712::TypedData;
+#[cfg(feature = "eip712")]
+use alloy_sol_types::{Eip712Domain, SolStruct};
+
+/// A [`SignerSync`] wrapper retrying failed signatures whose error is transient, e.g. for signers
+/// backed by a remote KMS or HSM.
+///
+/// An operation is attempted up to [`max_attempts`](Self::with_max_attempts) times, which is 3
+/// by default. Errors for which the `is_transient` predicate returns `false` are returned
+/// immediately. Between attempts, the current thread sleeps for the
+/// [backoff](Self::with_backoff), 100ms by default, doubled after each retry.
+///
+/// Local signers never fail transiently, so wrapping them only adds the predicate calls.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_signer::{Error, RetryingSigner};
+/// use std::time::Duration;
+/// # fn wrap<S: alloy_signer::SignerSync>(signer: S) {
+/// let signer = RetryingSigner::new(signer, |err: &Error| !err.is_unsupported())
+///     .with_max_attempts(5)
+///     .with_backoff(Duration::from_millis(50));
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryingSigner<W, F> {
+    inner: W,
+    is_transient: F,
+    max_attempts: u32,
+    backoff: Duration,
+}
+
+impl<W, F: Fn(&Error) -> bool> RetryingSigner<W, F> {
+    /// Wraps the signer, retrying errors for which `is_transient` returns `true`.
+    pub const fn new(inner: W, is_transient: F) -> Self {
+        Self { inner, is_transient, max_attempts: 3, backoff: Duration::from_millis(100) }
+    }
+
+    /// Sets the maximum number of attempts of each operation, including the first one.
+    ///
+    /// A value of `0` is treated as `1`.
+    pub const fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Sets the delay before the first retry, which is doubled after each retry.
+    pub const fn with_backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Returns a reference to the wrapped signer.
+    pub const fn inner(&self) -> &W {
+        &self.inner
+    }
+
+    /// Consumes the wrapper, returning the wrapped signer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    fn retry<T>(&self, mut op: impl FnMut() -> Result<T>) -> Result<T> {
+        let mut backoff = self.backoff;
+        let mut attempt = 1;
+        loop {
+            match op() {
+                Err(err) if attempt < self.max_attempts && (self.is_transient)(&err) => {
+                    thread::sleep(backoff);
+                    backoff = backoff.saturating_mul(2);
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl<Sig, W, F> SignerSync<Sig> for RetryingSigner<W, F>
+where
+    W: SignerSync<Sig>,
+    F: Fn(&Error) -> bool,
+{
+    fn sign_hash_sync(&self, hash: &B256) -> Result<Sig> {
+        self.retry(|| self.inner.sign_hash_sync(hash))
+    }
+
+    fn sign_message_sync(&self, message: &[u8]) -> Result<Sig> {
+        self.retry(|| self.inner.sign_message_sync(message))
+    }
+
+    #[cfg(feature = "eip712")]
+    fn sign_typed_data_sync<T: SolStruct>(&self, payload: &T, domain: &Eip712Domain) -> Result<Sig>
+    where
+        Self: Sized,
+    {
+        self.retry(|| self.inner.sign_typed_data_sync(payload, domain))
+    }
+
+    #[cfg(feature = "eip712")]
+    fn sign_dynamic_typed_data_sync(&self, payload: &TypedData) -> Result<Sig> {
+        self.retry(|| self.inner.sign_dynamic_typed_data_sync(payload))
+    }
+
+    fn chain_id_sync(&self) -> Option<ChainId> {
+        self.inner.chain_id_sync()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UnsupportedSignerOperation;
+    use alloy_primitives::Signature;
+    use std::cell::Cell;
+
+    /// A signer failing with a transient error a given number of times.
+    struct FlakySigner {
+        failures: Cell<u32>,
+        attempts: Cell<u32>,
+    }
+
+    impl FlakySigner {
+        fn new(failures: u32) -> Self {
+            Self { failures: Cell::new(failures), attempts: Cell::new(0) }
+        }
+    }
+
+    impl SignerSync for FlakySigner {
+        fn sign_hash_sync(&self, _hash: &B256) -> Result<Signature> {
+            self.attempts.set(self.attempts.get() + 1);
+            if self.failures.get() > 0 {
+                self.failures.set(self.failures.get() - 1);
+                return Err(Error::other("backend unavailable"));
+            }
+            Ok(Signature::test_signature())
+        }
+
+        fn chain_id_sync(&self) -> Option<ChainId> {
+            Some(1)
+        }
+    }
+
+    fn is_transient(err: &Error) -> bool {
+        matches!(err, Error::Other(_))
+    }
+
+    #[test]
+    fn retries_transient_errors() {
+        let signer = RetryingSigner::new(FlakySigner::new(2), is_transient)
+            .with_backoff(Duration::from_millis(1));
+        assert_eq!(signer.sign_hash_sync(&B256::ZERO).unwrap(), Signature::test_signature());
+        assert_eq!(signer.inner().attempts.get(), 3);
+        assert_eq!(signer.chain_id_sync(), Some(1));
+
+        let signer = RetryingSigner::new(FlakySigner::new(2), is_transient)
+            .with_max_attempts(2)
+            .with_backoff(Duration::ZERO);
+        assert!(signer.sign_message_sync(b"hello").is_err());
+        assert_eq!(signer.into_inner().attempts.get(), 2);
+    }
+
+    #[test]
+    fn returns_permanent_errors() {
+        struct Unsupported(Cell<u32>);
+        impl SignerSync for Unsupported {
+            fn sign_hash_sync(&self, _hash: &B256) -> Result<Signature> {
+                self.0.set(self.0.get() + 1);
+                Err(Error::UnsupportedOperation(UnsupportedSignerOperation::SignHash))
+            }
+
+            fn chain_id_sync(&self) -> Option<ChainId> {
+                None
+            }
+        }
+
+        let signer = RetryingSigner::new(Unsupported(Cell::new(0)), is_transient);
+        assert!(signer.sign_hash_sync(&B256::ZERO).unwrap_err().is_unsupported());
+        assert_eq!(signer.inner().0.get(), 1);
+    }
+}
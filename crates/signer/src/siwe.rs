@@ -0,0 +1,204 @@
+//! [EIP-4361] Sign-In with Ethereum messages.
+//!
+//! [EIP-4361]: https://eips.ethereum.org/EIPS/eip-4361
+
+use crate::{Error, Result};
+use alloy_primitives::{Address, ChainId, Signature};
+use std::fmt;
+
+/// An [EIP-4361] Sign-In with Ethereum message.
+///
+/// The message is signed as an [EIP-191] personal message over its
+/// [canonical text representation](Self::to_message_string).
+///
+/// Only the required fields and the optional statement are supported.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::address;
+/// use alloy_signer::siwe::SiweMessage;
+///
+/// let message = SiweMessage::new(
+///     "example.com",
+///     address!("6813Eb9362372EEF6200f3b1dbC3f819671cBA69"),
+///     "https://example.com/login",
+///     1,
+///     "32891756",
+///     "2021-09-30T16:25:24Z",
+/// )
+/// .with_statement("I accept the Terms of Service: https://example.com/tos");
+///
+/// assert!(message.to_message_string().starts_with("example.com wants you to sign in"));
+/// ```
+///
+/// [EIP-4361]: https://eips.ethereum.org/EIPS/eip-4361
+/// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SiweMessage {
+    /// The RFC 3986 authority requesting the signing, e.g. `example.com`.
+    pub domain: String,
+    /// The address performing the signing.
+    pub address: Address,
+    /// An optional human-readable statement. Must not contain newlines.
+    pub statement: Option<String>,
+    /// The RFC 3986 URI referring to the resource that is the subject of the signing.
+    pub uri: String,
+    /// The version of the message, which must be `1`.
+    pub version: String,
+    /// The [EIP-155] chain ID to which the session is bound.
+    ///
+    /// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+    pub chain_id: ChainId,
+    /// A randomized token used to prevent replay attacks, at least 8 alphanumeric characters.
+    pub nonce: String,
+    /// The RFC 3339 datetime of the moment the message was issued.
+    pub issued_at: String,
+}
+
+impl SiweMessage {
+    /// Creates a new version `1` message without a statement.
+    pub fn new(
+        domain: impl Into<String>,
+        address: Address,
+        uri: impl Into<String>,
+        chain_id: ChainId,
+        nonce: impl Into<String>,
+        issued_at: impl Into<String>,
+    ) -> Self {
+        Self {
+            domain: domain.into(),
+            address,
+            statement: None,
+            uri: uri.into(),
+            version: "1".into(),
+            chain_id,
+            nonce: nonce.into(),
+            issued_at: issued_at.into(),
+        }
+    }
+
+    /// Sets the statement of the message.
+    pub fn with_statement(mut self, statement: impl Into<String>) -> Self {
+        self.statement = Some(statement.into());
+        self
+    }
+
+    /// Sets the version of the message.
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = version.into();
+        self
+    }
+
+    /// Returns the canonical [EIP-4361] text representation of the message, i.e. the bytes
+    /// that are signed.
+    ///
+    /// [EIP-4361]: https://eips.ethereum.org/EIPS/eip-4361
+    pub fn to_message_string(&self) -> String {
+        self.to_string()
+    }
+
+    /// Verifies that the signature over this message was produced by
+    /// [`address`](Self::address).
+    pub fn verify(&self, signature: &Signature) -> Result<()> {
+        let recovered = signature.recover_address_from_msg(self.to_message_string())?;
+        if recovered != self.address {
+            return Err(Error::AddressMismatch { expected: self.address, recovered });
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for SiweMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} wants you to sign in with your Ethereum account:", self.domain)?;
+        writeln!(f, "{}", self.address)?;
+        writeln!(f)?;
+        if let Some(statement) = &self.statement {
+            writeln!(f, "{statement}")?;
+        }
+        writeln!(f)?;
+        writeln!(f, "URI: {}", self.uri)?;
+        writeln!(f, "Version: {}", self.version)?;
+        writeln!(f, "Chain ID: {}", self.chain_id)?;
+        writeln!(f, "Nonce: {}", self.nonce)?;
+        write!(f, "Issued At: {}", self.issued_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::secret_key_to_address;
+    use alloy_primitives::{address, eip191_hash_message};
+    use k256::ecdsa::SigningKey;
+
+    fn message() -> SiweMessage {
+        SiweMessage::new(
+            "service.invalid",
+            address!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"),
+            "https://service.invalid/login",
+            1,
+            "32891756",
+            "2021-09-30T16:25:24Z",
+        )
+    }
+
+    #[test]
+    fn message_string() {
+        let message = message().with_statement(
+            "I accept the ServiceOrg Terms of Service: https://service.invalid/tos",
+        );
+        assert_eq!(
+            message.to_message_string(),
+            "service.invalid wants you to sign in with your Ethereum account:
+0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2
+
+I accept the ServiceOrg Terms of Service: https://service.invalid/tos
+
+URI: https://service.invalid/login
+Version: 1
+Chain ID: 1
+Nonce: 32891756
+Issued At: 2021-09-30T16:25:24Z"
+        );
+    }
+
+    #[test]
+    fn message_string_without_statement() {
+        assert_eq!(
+            message().to_message_string(),
+            "service.invalid wants you to sign in with your Ethereum account:
+0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2
+
+
+URI: https://service.invalid/login
+Version: 1
+Chain ID: 1
+Nonce: 32891756
+Issued At: 2021-09-30T16:25:24Z"
+        );
+    }
+
+    fn sign(key: &SigningKey, message: &SiweMessage) -> Signature {
+        let hash = eip191_hash_message(message.to_message_string());
+        let (signature, recovery_id) = key.sign_prehash_recoverable(hash.as_slice()).unwrap();
+        Signature::from_signature_and_parity(signature, recovery_id).unwrap()
+    }
+
+    #[test]
+    fn verify() {
+        let key = SigningKey::from_bytes(&[1; 32].into()).unwrap();
+        let other = SigningKey::from_bytes(&[2; 32].into()).unwrap();
+        let message = SiweMessage { address: secret_key_to_address(&key), ..message() };
+
+        message.verify(&sign(&key, &message)).unwrap();
+
+        let err = message.verify(&sign(&other, &message)).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::AddressMismatch { expected, recovered }
+                if expected == message.address && recovered == secret_key_to_address(&other)
+        ));
+    }
+}
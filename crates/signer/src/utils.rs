@@ -1,6 +1,6 @@
 //! Utility functions for working with Ethereum signatures.
 
-use alloy_primitives::{keccak256, Address};
+use alloy_primitives::{keccak256, Address, Signature, B256};
 use elliptic_curve::sec1::ToEncodedPoint;
 use k256::{
     ecdsa::{SigningKey, VerifyingKey},
@@ -40,6 +40,14 @@ pub fn raw_public_key_to_address(pubkey: &[u8]) -> Address {
     Address::from_slice(&digest[12..])
 }
 
+/// Splits a signature into the `(bytes32 r, bytes32 s, uint8 v)` tuple expected by Solidity's
+/// `ecrecover`, with `v` normalized to `27` or `28` regardless of the signature's parity
+/// encoding.
+#[inline]
+pub fn signature_to_contract_tuple(sig: &Signature) -> (B256, B256, u8) {
+    (sig.r().into(), sig.s().into(), 27 + sig.v().y_parity() as u8)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -72,6 +80,21 @@ mod tests {
         assert_eq!(raw_public_key_to_address(&pubkey_bytes), addr);
     }
 
+    #[test]
+    fn test_signature_to_contract_tuple() {
+        let r =
+            B256::from(hex!("840cfc572845f5786e702984c2a582528cad4b49b2a10b9db1be7fca90058565"));
+        let s =
+            B256::from(hex!("25e7109ceb98168d95b09b18bbf6b685130e0562f233877d492b94eee0c5b6d1"));
+
+        let sig = Signature::from_scalars_and_parity(r, s, false).unwrap();
+        assert_eq!(signature_to_contract_tuple(&sig), (r, s, 27));
+
+        // EIP-155 `v = 38` on chain 1 has odd y parity
+        let sig = Signature::from_scalars_and_parity(r, s, 38u64).unwrap();
+        assert_eq!(signature_to_contract_tuple(&sig), (r, s, 28));
+    }
+
     #[test]
     #[should_panic]
     fn test_raw_public_key_to_address_panics() {